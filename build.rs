@@ -0,0 +1,16 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "cxx")]
+    {
+        let include = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("include");
+
+        // Exporting header dirs through cxx_build would need a `links` key, which would keep two semver-incompatible
+        // versions of this crate out of one dependency graph. Dependents add `kaff_sso::CXX_INCLUDE_DIRS` instead.
+        cxx_build::CFG.include_prefix = "kaff_sso";
+        cxx_build::bridge("src/cxx_impl.rs").include(&include).std("c++17").compile("kaff_sso_cxx");
+
+        println!("cargo:rerun-if-changed=src/cxx_impl.rs");
+        println!("cargo:rerun-if-changed=include/kaff_sso.h");
+    }
+}
@@ -0,0 +1,25 @@
+#pragma once
+
+#include "kaff_sso/src/cxx_impl.rs.h"
+
+#include <string_view>
+
+namespace kaff {
+
+/// Borrows the bytes of `s` as a `std::string_view`.
+inline std::string_view to_string_view(const UTF8 &s) {
+  rust::Str str = utf8_as_str(s);
+  return {str.data(), str.size()};
+}
+
+/// Borrows the bytes of `view` as a `std::string_view`.
+inline std::string_view to_string_view(const Utf8View &view) {
+  return {view.str.data(), view.str.size()};
+}
+
+/// Copies `sv` into a new `UTF8`. Throws `std::invalid_argument` if it is not valid UTF-8.
+inline rust::Box<UTF8> utf8_from(std::string_view sv) {
+  return utf8_new(rust::Str(sv.data(), sv.size()));
+}
+
+} // namespace kaff
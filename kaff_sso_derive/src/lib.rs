@@ -0,0 +1,147 @@
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Path, ext::IdentExt, parse_macro_input, parse_quote};
+
+/// Derives `fn as_utf8(&self) -> kaff_sso::UTF8` for an enum of unit variants, returning the variant name,
+/// without the `r#` of a raw identifier.
+///
+/// Each name is laid out in its inline bucket at compile time, so the call is a plain copy. Names longer than
+/// 256 bytes are built through `From<&str>` instead. Variants that carry data are rejected.
+///
+/// The generated code names the crate as `::kaff_sso`. Where it is renamed or re-exported, point the derive at
+/// it with `#[kaff_sso(crate = path::to::kaff_sso)]`.
+#[proc_macro_derive(AsUtf8, attributes(kaff_sso))]
+pub fn derive_as_utf8(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = crate_path(input)?;
+
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(&input.ident, "`AsUtf8` can only be derived for enums"));
+    };
+
+    if let Some(variant) = data.variants.iter().find(|variant| !matches!(variant.fields, Fields::Unit)) {
+        return Err(Error::new_spanned(variant, "`AsUtf8` only supports unit variants"));
+    }
+
+    let arms = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let value = bucket(&krate, &ident.unraw().to_string());
+        quote! { Self::#ident => #value, }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = if data.variants.is_empty() {
+        quote! { match *self { } }
+    } else {
+        quote! { match self { #(#arms)* } }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the variant name as a `UTF8`.
+            pub fn as_utf8(&self) -> #krate::UTF8 {
+                #body
+            }
+        }
+    })
+}
+
+/// Reads `#[kaff_sso(crate = ...)]`, defaulting to `::kaff_sso`.
+fn crate_path(input: &DeriveInput) -> syn::Result<Path> {
+    let mut krate = parse_quote!(::kaff_sso);
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("kaff_sso")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `kaff_sso` option; expected `crate = path`"))
+            }
+        })?;
+    }
+
+    Ok(krate)
+}
+
+/// Builds the `UTF8` literal for `name`, picking the bucket the same way `From<&str>` does.
+fn bucket(krate: &Path, name: &str) -> proc_macro2::TokenStream {
+    let len = name.len();
+    let (variant, size) = match len {
+        1..=8     => (quote!(B8),   8),
+        9..=16    => (quote!(B16),  16),
+        17..=64   => (quote!(B64),  64),
+        65..=128  => (quote!(B128), 128),
+        129..=256 => (quote!(B256), 256),
+        _         => return quote! { #krate::UTF8::from(#name) },
+    };
+
+    let mut bytes = name.as_bytes().to_vec();
+    bytes.resize(size, 0);
+
+    let buf = Literal::byte_string(&bytes);
+    let len = if size == 256 { Literal::u16_suffixed(len as u16) } else { Literal::u8_suffixed(len as u8) };
+    quote! { #krate::UTF8::#variant { buf: *#buf, len: #len } }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: DeriveInput) -> String {
+        expand(&input).unwrap().to_string()
+    }
+
+    fn error(input: DeriveInput) -> String {
+        expand(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn raw_identifiers_lose_their_prefix() {
+        let out = expand_str(parse_quote! { enum Keyword { r#type, r#match } });
+
+        assert!(out.contains("Self :: r#type => :: kaff_sso :: UTF8 :: B8 { buf : * b\"type\\0\\0\\0\\0\" , len : 4u8 }"), "{out}");
+        assert!(out.contains("b\"match\\0\\0\\0\""), "{out}");
+        assert!(!out.contains("r#type\\0"), "{out}");
+    }
+
+    #[test]
+    fn names_at_the_last_inline_bucket_stay_inline() {
+        let krate: Path = parse_quote!(::kaff_sso);
+
+        let full = bucket(&krate, &"A".repeat(256)).to_string();
+        assert!(full.contains("UTF8 :: B256") && full.contains("len : 256u16"), "{full}");
+
+        let over = bucket(&krate, &"A".repeat(257)).to_string();
+        assert!(over.contains("UTF8 :: from"), "{over}");
+
+        let b128 = bucket(&krate, &"A".repeat(128)).to_string();
+        assert!(b128.contains("UTF8 :: B128") && b128.contains("len : 128u8"), "{b128}");
+    }
+
+    #[test]
+    fn data_carrying_variants_are_rejected() {
+        assert!(error(parse_quote! { enum E { A, B(u8) } }).contains("only supports unit variants"));
+        assert!(error(parse_quote! { enum E { A { x: u8 } } }).contains("only supports unit variants"));
+        assert!(error(parse_quote! { struct S; }).contains("can only be derived for enums"));
+    }
+
+    #[test]
+    fn crate_path_can_be_overridden() {
+        let out = expand_str(parse_quote! {
+            #[kaff_sso(crate = my_crate::sso)]
+            enum E { A }
+        });
+
+        assert!(out.contains("-> my_crate :: sso :: UTF8"), "{out}");
+        assert!(!out.contains(":: kaff_sso"), "{out}");
+        assert!(error(parse_quote! { #[kaff_sso(krate = x)] enum E { A } }).contains("unknown `kaff_sso` option"));
+    }
+}
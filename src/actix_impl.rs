@@ -0,0 +1,42 @@
+use std::{future::Future, pin::Pin, task::{Context, Poll}};
+use actix_web::{Error, FromRequest, HttpRequest, HttpResponse, Responder, dev::Payload, http::header::{HeaderValue, ToStrError}};
+use crate::UTF8;
+
+impl Responder for UTF8 {
+    type Body = String;
+
+    /// Responds with `text/plain; charset=utf-8`, handing a `Boxed` buffer to the body without copying.
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        String::from(self).respond_to(req)
+    }
+}
+
+impl FromRequest for UTF8 {
+    type Error  = Error;
+    type Future = Utf8ExtractFut;
+
+    /// Reads the request body as text, with the same content-type, charset and size checks as `String`.
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        Utf8ExtractFut(String::from_request(req, payload))
+    }
+}
+
+/// Future returned by the `UTF8` body extractor.
+pub struct Utf8ExtractFut(<String as FromRequest>::Future);
+
+impl Future for Utf8ExtractFut {
+    type Output = Result<UTF8, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map_ok(UTF8::from)
+    }
+}
+
+impl TryFrom<&HeaderValue> for UTF8 {
+    type Error = ToStrError;
+
+    /// Copies a visible-ASCII header value straight into a `UTF8`, with the same rules as `HeaderValue::to_str`.
+    fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+        value.to_str().map(UTF8::from)
+    }
+}
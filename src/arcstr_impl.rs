@@ -0,0 +1,28 @@
+use arcstr::ArcStr;
+
+use crate::UTF8;
+
+impl From<&UTF8> for ArcStr {
+    fn from(value: &UTF8) -> Self {
+        if value.is_empty() { ArcStr::new() } else { ArcStr::from(&**value) }
+    }
+}
+
+impl From<UTF8> for ArcStr {
+    fn from(value: UTF8) -> Self {
+        ArcStr::from(&value)
+    }
+}
+
+impl From<&ArcStr> for UTF8 {
+    fn from(value: &ArcStr) -> Self {
+        UTF8::from(value.as_str())
+    }
+}
+
+impl From<ArcStr> for UTF8 {
+    /// `ArcStr` shares its heap data, so the text is always copied.
+    fn from(value: ArcStr) -> Self {
+        UTF8::from(&value)
+    }
+}
@@ -0,0 +1,139 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::UTF8;
+
+/// A `UTF8` that scans its text once, at construction, and remembers whether it is pure ASCII.
+///
+/// Character counting, case mapping and case-insensitive comparison then pick their byte-wise paths from the
+/// cached flag instead of rescanning, and equality rejects an ASCII and a non-ASCII value without looking at
+/// either text. Like [`HashedUTF8`](crate::HashedUTF8), two values are equal when they hold the same text.
+#[derive(Clone)]
+pub struct AsciiUTF8 {
+    value: UTF8,
+    ascii: bool,
+}
+
+impl AsciiUTF8 {
+    /// Scans `value` and wraps it.
+    pub fn new(value: UTF8) -> Self {
+        let ascii = value.elements().is_ascii();
+        Self { value, ascii }
+    }
+
+    /// Returns the cached flag.
+    pub fn is_ascii(&self) -> bool {
+        self.ascii
+    }
+
+    /// Returns the wrapped string.
+    pub fn get(&self) -> &UTF8 {
+        &self.value
+    }
+
+    /// Unwraps the string.
+    pub fn into_inner(self) -> UTF8 {
+        self.value
+    }
+
+    /// Counts the characters; ASCII text is answered by its length.
+    pub fn char_count(&self) -> usize {
+        if self.ascii { self.value.len() } else { self.value.text().chars().count() }
+    }
+
+    /// Lowercases into a new value. ASCII text is lowered in place in a copy and stays ASCII without a rescan.
+    pub fn to_lowercase_utf8(&self) -> Self {
+        if self.ascii {
+            return Self { value: self.value.map_ascii(<[u8]>::make_ascii_lowercase), ascii: true };
+        }
+
+        Self::new(UTF8::from(self.value.text().to_lowercase()))
+    }
+
+    /// Uppercases into a new value, with the same ASCII fast path as [`to_lowercase_utf8`](Self::to_lowercase_utf8).
+    pub fn to_uppercase_utf8(&self) -> Self {
+        if self.ascii {
+            return Self { value: self.value.map_ascii(<[u8]>::make_ascii_uppercase), ascii: true };
+        }
+
+        Self::new(UTF8::from(self.value.text().to_uppercase()))
+    }
+
+    /// Compares with `other` ignoring case: byte-wise when both are ASCII, by full Unicode lowercase mappings
+    /// otherwise.
+    pub fn eq_ignore_case(&self, other: &Self) -> bool {
+        if self.ascii && other.ascii {
+            return self.value.elements().eq_ignore_ascii_case(other.value.elements());
+        }
+
+        self.value.eq_ignore_case(other.value.text())
+    }
+
+    /// Orders by lowercased text without allocating: by lowercased bytes when both are ASCII, by lowercased
+    /// characters otherwise.
+    pub fn cmp_ignore_case(&self, other: &Self) -> Ordering {
+        let (left, right) = (self.value.text(), other.value.text());
+        if self.ascii && other.ascii {
+            return left.bytes().map(|b| b.to_ascii_lowercase()).cmp(right.bytes().map(|b| b.to_ascii_lowercase()));
+        }
+
+        left.chars().flat_map(char::to_lowercase).cmp(right.chars().flat_map(char::to_lowercase))
+    }
+}
+
+impl std::fmt::Debug for AsciiUTF8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl std::ops::Deref for AsciiUTF8 {
+    type Target = UTF8;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl PartialEq for AsciiUTF8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.ascii == other.ascii && self.value.elements() == other.value.elements()
+    }
+}
+impl Eq for AsciiUTF8 { }
+
+impl PartialOrd for AsciiUTF8 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AsciiUTF8 {
+    /// Orders by bytes, which for UTF-8 matches `str` ordering, so ASCII and non-ASCII text compare alike.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.elements().cmp(other.value.elements())
+    }
+}
+
+impl Hash for AsciiUTF8 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl From<UTF8> for AsciiUTF8 {
+    fn from(value: UTF8) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for AsciiUTF8 {
+    fn from(value: &str) -> Self {
+        Self::new(UTF8::from(value))
+    }
+}
+
+impl From<AsciiUTF8> for UTF8 {
+    fn from(value: AsciiUTF8) -> Self {
+        value.value
+    }
+}
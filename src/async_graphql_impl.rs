@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+use async_graphql::{
+    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
+    ScalarType, ServerResult, Value, parser::types::Field, registry::Registry,
+};
+use crate::UTF8;
+
+// `UTF8` registers as the built-in `String` scalar, so schemas and clients see a plain GraphQL string.
+
+impl ScalarType for UTF8 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Ok(UTF8::from(s)),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(String::from(&**self))
+    }
+}
+
+impl InputType for UTF8 {
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("String")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <String as OutputType>::create_type_info(registry)
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <Self as ScalarType>::parse(value.unwrap_or_default()).map_err(InputValueError::propagate)
+    }
+
+    fn to_value(&self) -> Value {
+        <Self as ScalarType>::to_value(self)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl OutputType for UTF8 {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("String")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <String as OutputType>::create_type_info(registry)
+    }
+
+    async fn resolve(&self, _: &ContextSelectionSet<'_>, _: &Positioned<Field>) -> ServerResult<Value> {
+        Ok(<Self as ScalarType>::to_value(self))
+    }
+}
@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+
+use crate::UTF8;
+
+/// An interned string: a `Copy` handle to one shared `UTF8` per distinct text.
+///
+/// Interning the same text twice yields the same handle, so equality and hashing are a pointer compare.
+/// Ordering still follows the text. Interned strings live in a process-wide table and are never freed,
+/// which suits a bounded vocabulary such as property names or keywords.
+#[derive(Clone, Copy)]
+pub struct Atom(&'static UTF8);
+
+fn table() -> &'static Mutex<HashMap<&'static str, Atom>> {
+    static TABLE: OnceLock<Mutex<HashMap<&'static str, Atom>>> = OnceLock::new();
+    TABLE.get_or_init(Default::default)
+}
+
+impl Atom {
+    /// Returns the atom for `text`, interning it on first use.
+    pub fn new(text: &str) -> Self {
+        let mut table = table().lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(&atom) = table.get(text) {
+            return atom;
+        }
+
+        let value: &'static UTF8 = Box::leak(Box::new(UTF8::from(text)));
+        let atom = Atom(value);
+        table.insert(atom.as_str(), atom);
+        atom
+    }
+
+    /// Returns the atom for `text` if it has been interned already, without interning it.
+    pub fn get(text: &str) -> Option<Self> {
+        table().lock().unwrap_or_else(|poisoned| poisoned.into_inner()).get(text).copied()
+    }
+
+    /// Returns the interned text.
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+
+    /// Returns the interned `UTF8`.
+    pub fn as_utf8(self) -> &'static UTF8 {
+        self.0
+    }
+}
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+impl Eq for Atom { }
+
+impl Hash for Atom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.0, state);
+    }
+}
+
+impl PartialOrd for Atom {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Atom {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other { Ordering::Equal } else { self.as_str().cmp(other.as_str()) }
+    }
+}
+
+impl PartialEq<str> for Atom {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Atom({:?})", self.as_str())
+    }
+}
+
+impl From<&str> for Atom {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<Atom> for UTF8 {
+    fn from(atom: Atom) -> Self {
+        atom.0.clone()
+    }
+}
+
+impl AsRef<str> for Atom {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
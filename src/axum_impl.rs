@@ -0,0 +1,9 @@
+use axum_core::response::{IntoResponse, Response};
+use crate::UTF8;
+
+impl IntoResponse for UTF8 {
+    /// Responds with `text/plain; charset=utf-8`, handing a `Boxed` buffer to the body without copying.
+    fn into_response(self) -> Response {
+        String::from(self).into_response()
+    }
+}
@@ -0,0 +1,42 @@
+use serde::de::{Error as _, Unexpected};
+use bson::{Bson, RawBsonRef, error::Error};
+use crate::UTF8;
+
+impl From<UTF8> for Bson {
+    fn from(value: UTF8) -> Self {
+        Bson::String(value.into())
+    }
+}
+
+impl TryFrom<Bson> for UTF8 {
+    type Error = Error;
+
+    /// Takes a `Bson::String`, adopting its buffer when it is too long to inline.
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        match value {
+            Bson::String(s) => Ok(UTF8::from(s)),
+            other => Err(unexpected(other.element_type())),
+        }
+    }
+}
+
+impl TryFrom<&Bson> for UTF8 {
+    type Error = Error;
+
+    fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+        value.as_str().map(UTF8::from).ok_or_else(|| unexpected(value.element_type()))
+    }
+}
+
+impl TryFrom<RawBsonRef<'_>> for UTF8 {
+    type Error = Error;
+
+    /// Copies a string straight out of a raw BSON document, without an intermediate `String`.
+    fn try_from(value: RawBsonRef<'_>) -> Result<Self, Self::Error> {
+        value.as_str().map(UTF8::from).ok_or_else(|| unexpected(value.element_type()))
+    }
+}
+
+fn unexpected(actual: bson::spec::ElementType) -> Error {
+    Error::invalid_type(Unexpected::Other(&format!("{actual:?}")), &"a string")
+}
@@ -0,0 +1,62 @@
+use bstr::{BStr, BString, ByteSlice};
+
+use crate::{Blob, Str};
+
+impl Str<u8> {
+    /// Borrows the stored bytes as a `&BStr`, giving access to `ByteSlice` methods such as `find`,
+    /// `fields` and `lines`, which also work on bytes that are not valid UTF-8.
+    pub fn as_bstr(&self) -> &BStr {
+        BStr::new(self.elements())
+    }
+}
+
+impl Blob {
+    /// Borrows the stored bytes as a `&BStr`.
+    pub fn as_bstr(&self) -> &BStr {
+        self.0.as_bstr()
+    }
+}
+
+impl AsRef<BStr> for Blob {
+    fn as_ref(&self) -> &BStr {
+        self.as_bstr()
+    }
+}
+
+impl AsRef<[u8]> for Blob {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<&BStr> for Blob {
+    fn from(value: &BStr) -> Self {
+        Blob::from(value.as_bytes())
+    }
+}
+
+impl From<BString> for Blob {
+    /// Takes over the heap buffer past 256 bytes; shorter values are copied into an inline bucket.
+    fn from(value: BString) -> Self {
+        let bytes = Vec::from(value);
+
+        match bytes.len() {
+            0..=256 => Blob::from(&bytes[..]),
+            len     => Blob(Str::Boxed { buf: bytes.into_boxed_slice(), len }),
+        }
+    }
+}
+
+impl From<Blob> for BString {
+    /// Hands a `Boxed` buffer over without copying.
+    fn from(value: Blob) -> Self {
+        match value.0 {
+            Str::Boxed { buf, len } => {
+                let mut bytes = Vec::from(buf);
+                bytes.truncate(len);
+                BString::from(bytes)
+            }
+            _ => BString::from(value.as_bytes()),
+        }
+    }
+}
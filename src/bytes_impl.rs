@@ -0,0 +1,90 @@
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut, Bytes};
+
+use crate::io::Utf8Cursor;
+use crate::{Blob, Str, UTF8};
+
+impl From<Str<u8>> for Bytes {
+    /// Takes over a `Boxed` buffer without copying; inline values are copied.
+    fn from(value: Str<u8>) -> Self {
+        match value {
+            Str::Boxed { buf, len } if len == buf.len() => Bytes::from(buf),
+            Str::Boxed { buf, len } => {
+                let mut vec = Vec::from(buf);
+                vec.truncate(len);
+                Bytes::from(vec)
+            }
+
+            Str::Empty => Bytes::new(),
+            value => Bytes::copy_from_slice(value.elements()),
+        }
+    }
+}
+
+impl From<Blob> for Bytes {
+    fn from(value: Blob) -> Self {
+        Bytes::from(value.0)
+    }
+}
+
+impl From<Bytes> for Blob {
+    /// Copies short payloads into an inline bucket. Longer ones reuse the allocation when `value`
+    /// is its only handle and was built from a `Vec`.
+    fn from(value: Bytes) -> Self {
+        match value.len() {
+            0..=256 => Blob::from(&value[..]),
+            len     => Blob(Str::Boxed { buf: Vec::from(value).into_boxed_slice(), len }),
+        }
+    }
+}
+
+impl TryFrom<Bytes> for UTF8 {
+    type Error = crate::Error;
+
+    /// Validates the bytes, then converts like `From<Bytes> for Blob`.
+    fn try_from(value: Bytes) -> Result<Self, Self::Error> {
+        std::str::from_utf8(&value)?;
+        Ok(Blob::from(value).0)
+    }
+}
+
+/// Appends to the blob, growing it through the inline buckets like `io::Write`.
+unsafe impl BufMut for Blob {
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.0.len()
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let len = self.0.len() + cnt;
+        assert!(len <= self.0.capacity(), "advance_mut past the end of the chunk");
+        self.0.set_len(len);
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.0.capacity() == self.0.len() {
+            self.0.reserve(64);
+        }
+
+        UninitSlice::new(self.0.spare_mut())
+    }
+
+    fn put_slice(&mut self, src: &[u8]) {
+        self.0.extend_from_slice(src);
+    }
+}
+
+/// Reads the remaining bytes in place, like `Buf` for `io::Cursor`.
+impl<S: std::borrow::Borrow<Str<u8>>> Buf for Utf8Cursor<S> {
+    fn remaining(&self) -> usize {
+        self.remaining_bytes().len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.remaining_bytes()
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(cnt <= self.remaining(), "cannot advance past the end of the buffer");
+        self.set_position(self.position() + cnt as u64);
+    }
+}
@@ -0,0 +1,52 @@
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::UTF8;
+
+impl UTF8 {
+    /// Borrows the text as a `Utf8Path`.
+    pub fn as_utf8_path(&self) -> &Utf8Path {
+        Utf8Path::new(self.text())
+    }
+
+    /// Joins `path` onto this one like `Utf8Path::join`, adding a separator when needed, but renders
+    /// through a stack buffer so short results stay inline. An absolute `path` replaces this one.
+    pub fn join_path(&self, path: impl AsRef<Utf8Path>) -> UTF8 {
+        let (base, path) = (self.text(), path.as_ref());
+
+        if path.is_absolute() || base.is_empty() {
+            return UTF8::from(path.as_str());
+        }
+
+        let separator = if base.ends_with(std::path::is_separator) { "" } else { std::path::MAIN_SEPARATOR_STR };
+        UTF8::from_fmt(format_args!("{base}{separator}{path}"))
+    }
+
+    /// Returns the final component, like `Utf8Path::file_name`.
+    pub fn file_name(&self) -> Option<UTF8> {
+        self.as_utf8_path().file_name().map(UTF8::from)
+    }
+
+    /// Returns the extension of the final component, like `Utf8Path::extension`.
+    pub fn extension(&self) -> Option<UTF8> {
+        self.as_utf8_path().extension().map(UTF8::from)
+    }
+}
+
+impl From<&Utf8Path> for UTF8 {
+    fn from(path: &Utf8Path) -> Self {
+        UTF8::from(path.as_str())
+    }
+}
+
+impl From<Utf8PathBuf> for UTF8 {
+    /// Long paths keep the `Utf8PathBuf`'s buffer; short ones are copied inline.
+    fn from(path: Utf8PathBuf) -> Self {
+        UTF8::from(path.into_string())
+    }
+}
+
+impl From<UTF8> for Utf8PathBuf {
+    fn from(value: UTF8) -> Self {
+        Utf8PathBuf::from(String::from(value))
+    }
+}
@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::UTF8;
+
+// Owned pieces stay inline; boxing them would add an allocation per piece.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+enum Segment<'a> {
+    Owned(UTF8),
+    Borrowed(&'a str),
+}
+
+impl Segment<'_> {
+    fn as_str(&self) -> &str {
+        match self {
+            Segment::Owned(value) => value,
+            Segment::Borrowed(value) => value,
+        }
+    }
+}
+
+/// Pieces held in the chain itself before it starts spilling to the heap.
+const INLINE: usize = 5;
+
+/// A string made of several `UTF8` and `&str` pieces that are only joined when needed.
+///
+/// The first five pieces are stored inline, so a chain of a few pieces allocates nothing until it is
+/// collected into a long string; later pieces spill into a `Vec`. Displaying and comparing walk the pieces
+/// in place; [`collect`](Self::collect) copies them once into the bucket that fits the total length.
+/// Chains compare with each other and with `str` by text, like `str`, and with `UTF8` in its length-first order.
+#[derive(Clone, Default)]
+pub struct Utf8Chain<'a> {
+    inline:  [Option<Segment<'a>>; INLINE],
+    inlined: usize,
+    spilled: Vec<Segment<'a>>,
+    len:     usize,
+}
+
+impl<'a> Utf8Chain<'a> {
+    /// Creates an empty chain.
+    pub fn new() -> Self {
+        Self { inline: Default::default(), inlined: 0, spilled: Vec::new(), len: 0 }
+    }
+
+    /// Appends a borrowed piece.
+    pub fn push_str(&mut self, value: &'a str) -> &mut Self {
+        if !value.is_empty() {
+            self.len += value.len();
+            self.push_segment(Segment::Borrowed(value));
+        }
+
+        self
+    }
+
+    /// Appends an owned piece.
+    pub fn push(&mut self, value: UTF8) -> &mut Self {
+        if !value.is_empty() {
+            self.len += value.len();
+            self.push_segment(Segment::Owned(value));
+        }
+
+        self
+    }
+
+    fn push_segment(&mut self, segment: Segment<'a>) {
+        match self.inline.get_mut(self.inlined) {
+            Some(slot) => {
+                *slot = Some(segment);
+                self.inlined += 1;
+            }
+            None => self.spilled.push(segment),
+        }
+    }
+
+    /// Returns the total length in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the chain holds no text.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterates over the pieces in order.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.inline.iter().flatten().chain(&self.spilled).map(Segment::as_str)
+    }
+
+    /// Joins the pieces into one `UTF8` in the smallest bucket that holds them. A chain of a single owned
+    /// piece hands it back without copying.
+    pub fn collect(mut self) -> UTF8 {
+        if self.inlined == 1
+            && let Some(Segment::Owned(_)) = &self.inline[0]
+            && let Some(Segment::Owned(value)) = self.inline[0].take()
+        {
+            return value;
+        }
+
+        if self.len <= 256 {
+            let mut buf = [0u8; 256];
+            let mut at  = 0;
+
+            for segment in self.segments() {
+                buf[at..at + segment.len()].copy_from_slice(segment.as_bytes());
+                at += segment.len();
+            }
+
+            return UTF8::from(&buf[..at]);
+        }
+
+        let mut text = String::with_capacity(self.len);
+        self.segments().for_each(|segment| text.push_str(segment));
+        UTF8::from(text)
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.segments().flat_map(str::bytes)
+    }
+}
+
+impl fmt::Display for Utf8Chain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.segments().try_for_each(|segment| f.write_str(segment))
+    }
+}
+
+impl fmt::Debug for Utf8Chain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.segments()).finish()
+    }
+}
+
+impl PartialEq for Utf8Chain<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.bytes().eq(other.bytes())
+    }
+}
+impl Eq for Utf8Chain<'_> { }
+
+impl PartialOrd for Utf8Chain<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Utf8Chain<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes().cmp(other.bytes())
+    }
+}
+
+impl PartialEq<str> for Utf8Chain<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.len == other.len() && self.bytes().eq(other.bytes())
+    }
+}
+
+impl PartialEq<&str> for Utf8Chain<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<UTF8> for Utf8Chain<'_> {
+    fn eq(&self, other: &UTF8) -> bool {
+        self.len == other.len() && self.bytes().eq(other.elements().iter().copied())
+    }
+}
+
+impl PartialOrd<str> for Utf8Chain<'_> {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.bytes().cmp(other.bytes()))
+    }
+}
+
+/// Orders like `Ord for Str`, by length and then by bytes, rather than by text as chains compare with each other.
+impl PartialOrd<UTF8> for Utf8Chain<'_> {
+    fn partial_cmp(&self, other: &UTF8) -> Option<Ordering> {
+        Some(self.len.cmp(&other.len()).then_with(|| self.bytes().cmp(other.elements().iter().copied())))
+    }
+}
+
+impl<'a> From<&'a str> for Utf8Chain<'a> {
+    fn from(value: &'a str) -> Self {
+        let mut chain = Self::new();
+        chain.push_str(value);
+        chain
+    }
+}
+
+impl From<UTF8> for Utf8Chain<'_> {
+    fn from(value: UTF8) -> Self {
+        let mut chain = Self::new();
+        chain.push(value);
+        chain
+    }
+}
+
+impl From<Utf8Chain<'_>> for UTF8 {
+    fn from(chain: Utf8Chain<'_>) -> Self {
+        chain.collect()
+    }
+}
+
+impl<'a> Extend<&'a str> for Utf8Chain<'a> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| { self.push_str(value); });
+    }
+}
+
+impl Extend<UTF8> for Utf8Chain<'_> {
+    fn extend<I: IntoIterator<Item = UTF8>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| { self.push(value); });
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Utf8Chain<'a> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut chain = Self::new();
+        chain.extend(iter);
+        chain
+    }
+}
+
+impl FromIterator<UTF8> for Utf8Chain<'_> {
+    fn from_iter<I: IntoIterator<Item = UTF8>>(iter: I) -> Self {
+        let mut chain = Self::new();
+        chain.extend(iter);
+        chain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pieces_past_the_inline_slots_keep_their_order() {
+        let pieces = ["a", "bb", "ccc", "dddd", "eeeee", "ffffff", "g"];
+        let chain: Utf8Chain = pieces.iter().copied().collect();
+
+        assert_eq!(chain.segments().collect::<Vec<_>>(), pieces);
+        assert_eq!(chain.len(), pieces.concat().len());
+        assert_eq!(chain.collect(), UTF8::from(pieces.concat()));
+    }
+
+    #[test]
+    fn a_single_owned_piece_is_handed_back() {
+        let value = UTF8::from("x".repeat(300));
+        assert_eq!(Utf8Chain::from(value.clone()).collect(), value);
+    }
+}
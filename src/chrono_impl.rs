@@ -0,0 +1,17 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, TimeZone};
+
+use crate::UTF8;
+
+impl UTF8 {
+    /// Renders `value` as RFC 3339 with as many fractional digits as needed (chrono's `%+`).
+    pub fn from_chrono<Tz: TimeZone>(value: &DateTime<Tz>) -> Self where Tz::Offset: Display {
+        Self::from_fmt(format_args!("{}", value.format("%+")))
+    }
+
+    /// Renders `value` with a `strftime`-style format string. Fails if `format` is not a valid description.
+    pub fn format_chrono<Tz: TimeZone>(value: &DateTime<Tz>, format: &str) -> Result<Self, std::fmt::Error> where Tz::Offset: Display {
+        Self::try_from_fmt(format_args!("{}", value.format(format)))
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Blob, UTF8};
+
+/// How [`UTF8::to_sort_key`] orders text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Collation {
+    /// By code point, which is the byte order of UTF-8.
+    Binary,
+    /// By code point after mapping each character to lowercase, the same mapping
+    /// [`eq_ignore_case`](UTF8::eq_ignore_case) uses.
+    CaseInsensitive,
+}
+
+impl UTF8 {
+    /// Encodes the text as a byte string whose `memcmp` order matches `collation`, for use as an ordered key in
+    /// B-tree or LSM storage. Keys are built in inline buckets when they fit, and ASCII text takes a
+    /// byte-wise path. With `feature = "icu_collator"`, `to_icu_sort_key` covers locale-aware orders.
+    pub fn to_sort_key(&self, collation: Collation) -> Blob {
+        match collation {
+            Collation::Binary => Blob(self.clone()),
+
+            Collation::CaseInsensitive if self.elements().is_ascii() => self.to_lowercase_utf8().into_byte_str(),
+
+            Collation::CaseInsensitive => {
+                let chars = self.chars().flat_map(char::to_lowercase).map(Ok::<_, std::convert::Infallible>);
+                match UTF8::try_from_chars(chars) { Ok(key) => key.into_byte_str() }
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use compact_str::CompactString;
+
+use crate::UTF8;
+
+impl From<UTF8> for CompactString {
+    /// Short strings land in `CompactString`'s inline storage; a `Boxed` buffer is handed over as a `String`.
+    fn from(value: UTF8) -> Self {
+        match value {
+            UTF8::Boxed { .. } => CompactString::from(String::from(value)),
+            UTF8::Empty => CompactString::const_new(""),
+            value => CompactString::new(&*value),
+        }
+    }
+}
+
+impl From<CompactString> for UTF8 {
+    /// Copies into an inline bucket when the string fits; longer ones take over the heap buffer.
+    fn from(value: CompactString) -> Self {
+        match value.len() {
+            0..=256 => UTF8::from(value.as_str()),
+            _       => UTF8::from(value.into_string()),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::UTF8;
+
+#[cxx::bridge(namespace = "kaff")]
+mod bridge {
+    /// A borrowed view of a `UTF8`, valid while the string it came from is alive.
+    struct Utf8View<'a> {
+        str: &'a str,
+    }
+
+    extern "Rust" {
+        type UTF8;
+
+        fn utf8_new(s: &str) -> Box<UTF8>;
+        fn utf8_clone(s: &UTF8) -> Box<UTF8>;
+        fn utf8_as_str(s: &UTF8) -> &str;
+        unsafe fn utf8_view<'a>(s: &'a UTF8) -> Utf8View<'a>;
+        fn utf8_len(s: &UTF8) -> usize;
+    }
+}
+
+/// The directories a dependent's C++ build needs on its include path for `#include "kaff_sso.h"`: the
+/// shipped header and the one cxx generates for the bridge, along with `rust/cxx.h`. A build script gets them by
+/// listing `kaff_sso` with `feature = "cxx"` under `[build-dependencies]` and passing them to
+/// `cxx_build::bridge(..).includes(kaff_sso::CXX_INCLUDE_DIRS)`.
+pub const CXX_INCLUDE_DIRS: [&str; 2] = [
+    concat!(env!("CARGO_MANIFEST_DIR"), "/include"),
+    concat!(env!("OUT_DIR"), "/cxxbridge/include"),
+];
+
+fn utf8_new(s: &str) -> Box<UTF8> {
+    Box::new(UTF8::from(s))
+}
+
+fn utf8_clone(s: &UTF8) -> Box<UTF8> {
+    Box::new(s.clone())
+}
+
+fn utf8_as_str(s: &UTF8) -> &str {
+    s
+}
+
+fn utf8_view(s: &UTF8) -> bridge::Utf8View<'_> {
+    bridge::Utf8View { str: utf8_as_str(s) }
+}
+
+fn utf8_len(s: &UTF8) -> usize {
+    s.len()
+}
@@ -0,0 +1,14 @@
+use defmt::{Format, Formatter};
+use crate::{Blob, UTF8};
+
+impl Format for UTF8 {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", &**self)
+    }
+}
+
+impl Format for Blob {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=[u8]}", self.as_bytes())
+    }
+}
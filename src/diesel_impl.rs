@@ -0,0 +1,50 @@
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    serialize::{self, Output, ToSql},
+    sql_types::{Binary, Text},
+};
+use crate::{Blob, UTF8};
+
+// Same shape diesel uses for `String` and `Vec<u8>`: the derives on these proxies emit
+// `AsExpression` (plain, by reference and nullable) and `FromSqlRow` for the wrapped type.
+
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Text)]
+#[allow(dead_code)]
+struct Utf8Proxy(UTF8);
+
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Binary)]
+#[allow(dead_code)]
+struct BlobProxy(Blob);
+
+impl<DB: Backend> ToSql<Text, DB> for UTF8 where str: ToSql<Text, DB> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <str as ToSql<Text, DB>>::to_sql(self, out)
+    }
+}
+
+impl<DB: Backend> FromSql<Text, DB> for UTF8 where *const str: FromSql<Text, DB> {
+    /// Borrows the raw column as `&str` and copies it, so short text lands in an inline bucket.
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let ptr = <*const str as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(UTF8::from(unsafe { &*ptr }))
+    }
+}
+
+impl<DB: Backend> ToSql<Binary, DB> for Blob where [u8]: ToSql<Binary, DB> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <[u8] as ToSql<Binary, DB>>::to_sql(self.as_bytes(), out)
+    }
+}
+
+impl<DB: Backend> FromSql<Binary, DB> for Blob where *const [u8]: FromSql<Binary, DB> {
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let ptr = <*const [u8] as FromSql<Binary, DB>>::from_sql(bytes)?;
+        Ok(Blob::from(unsafe { &*ptr }))
+    }
+}
@@ -0,0 +1,319 @@
+use std::ops::Range;
+
+use unicode_segmentation::GraphemeCursor;
+
+use crate::UTF8;
+
+/// A `UTF8` with an editing cursor and an optional selection, both kept on grapheme cluster boundaries.
+///
+/// Positions are byte offsets. The cursor moves one grapheme at a time, and deleting or inserting text never
+/// leaves it inside a cluster, so `e` followed by a combining accent is stepped over and removed as one unit.
+/// The selection runs between an anchor and the cursor.
+#[derive(Clone, Debug)]
+pub struct Utf8Editor {
+    text:   UTF8,
+    cursor: usize,
+    anchor: Option<usize>,
+}
+
+impl Utf8Editor {
+    /// Starts editing `text` with the cursor at the end.
+    pub fn new(text: UTF8) -> Self {
+        Self { cursor: text.len(), text, anchor: None }
+    }
+
+    /// Returns the current text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Stops editing and returns the text.
+    pub fn into_inner(self) -> UTF8 {
+        self.text
+    }
+
+    /// Returns the cursor position in bytes.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to `pos`, clamped to the text and snapped back to the start of its grapheme.
+    /// Clears the selection.
+    pub fn set_cursor(&mut self, pos: usize) {
+        self.anchor = None;
+        self.cursor = self.snap_back(pos.min(self.text.len()));
+    }
+
+    /// Returns the selected byte range, if any text is selected.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        (anchor != self.cursor).then(|| anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// Returns the selected text, or `""`.
+    pub fn selected(&self) -> &str {
+        self.selection().map_or("", |range| &self.as_str()[range])
+    }
+
+    /// Selects `range`, snapping both ends to grapheme boundaries; the cursor is left at its end.
+    pub fn select(&mut self, range: Range<usize>) {
+        let start = self.snap_back(range.start.min(self.text.len()));
+        let end   = self.snap_back(range.end.min(self.text.len()));
+
+        self.anchor = Some(start);
+        self.cursor = end;
+    }
+
+    /// Selects the whole text.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.text.len();
+    }
+
+    /// Drops the selection, keeping the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Moves the cursor one grapheme left. With a selection, collapses it to its start instead.
+    pub fn move_left(&mut self) {
+        match self.selection() {
+            Some(range) => self.cursor = range.start,
+            None => self.cursor = self.prev_boundary(self.cursor),
+        }
+
+        self.anchor = None;
+    }
+
+    /// Moves the cursor one grapheme right. With a selection, collapses it to its end instead.
+    pub fn move_right(&mut self) {
+        match self.selection() {
+            Some(range) => self.cursor = range.end,
+            None => self.cursor = self.next_boundary(self.cursor),
+        }
+
+        self.anchor = None;
+    }
+
+    /// Extends the selection one grapheme to the left.
+    pub fn select_left(&mut self) {
+        self.anchor.get_or_insert(self.cursor);
+        self.cursor = self.prev_boundary(self.cursor);
+    }
+
+    /// Extends the selection one grapheme to the right.
+    pub fn select_right(&mut self) {
+        self.anchor.get_or_insert(self.cursor);
+        self.cursor = self.next_boundary(self.cursor);
+    }
+
+    /// Moves the cursor to the start of the text.
+    pub fn move_home(&mut self) {
+        self.anchor = None;
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the text.
+    pub fn move_end(&mut self) {
+        self.anchor = None;
+        self.cursor = self.text.len();
+    }
+
+    /// Inserts `text` at the cursor, replacing the selection if there is one. The cursor ends up after the
+    /// inserted text, moved forward to the end of the cluster if the insertion merged into the next one.
+    pub fn insert(&mut self, text: &str) {
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        let end   = range.start + text.len();
+
+        self.splice(range, text);
+        self.cursor = self.snap_forward(end);
+    }
+
+    /// Replaces the selection with `text`; without a selection this is the same as [`insert`](Self::insert).
+    pub fn replace_selection(&mut self, text: &str) {
+        self.insert(text);
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor. Returns `false` if there was nothing to delete.
+    pub fn backspace(&mut self) -> bool {
+        let range = self.selection().unwrap_or(self.prev_boundary(self.cursor)..self.cursor);
+        self.delete_range(range)
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor. Returns `false` if there was nothing to delete.
+    pub fn delete(&mut self) -> bool {
+        let range = self.selection().unwrap_or(self.cursor..self.next_boundary(self.cursor));
+        self.delete_range(range)
+    }
+
+    fn delete_range(&mut self, range: Range<usize>) -> bool {
+        self.anchor = None;
+
+        if range.is_empty() {
+            return false;
+        }
+
+        let start = range.start;
+        self.splice(range, "");
+        self.cursor = self.snap_back(start);
+        true
+    }
+
+    fn splice(&mut self, range: Range<usize>, text: &str) {
+        let old = self.as_str();
+
+        let mut next = UTF8::from(&old[..range.start]);
+        next.extend_from_slice(text.as_bytes());
+        next.extend_from_slice(&old.as_bytes()[range.end..]);
+
+        debug_assert_eq!(next.check_utf8(), Ok(()));
+        self.text   = next;
+        self.anchor = None;
+    }
+
+    fn prev_boundary(&self, pos: usize) -> usize {
+        let mut cursor = GraphemeCursor::new(pos, self.text.len(), true);
+        cursor.prev_boundary(self.as_str(), 0).expect("the whole text is one chunk").unwrap_or(0)
+    }
+
+    fn next_boundary(&self, pos: usize) -> usize {
+        let mut cursor = GraphemeCursor::new(pos, self.text.len(), true);
+        cursor.next_boundary(self.as_str(), 0).expect("the whole text is one chunk").unwrap_or(self.text.len())
+    }
+
+    fn is_boundary(&self, pos: usize) -> bool {
+        let mut cursor = GraphemeCursor::new(pos, self.text.len(), true);
+        cursor.is_boundary(self.as_str(), 0).expect("the whole text is one chunk")
+    }
+
+    fn snap_back(&self, mut pos: usize) -> usize {
+        while !self.as_str().is_char_boundary(pos) {
+            pos -= 1;
+        }
+
+        if self.is_boundary(pos) { pos } else { self.prev_boundary(pos) }
+    }
+
+    fn snap_forward(&self, pos: usize) -> usize {
+        if self.is_boundary(pos) { pos } else { self.next_boundary(pos) }
+    }
+}
+
+impl Default for Utf8Editor {
+    fn default() -> Self {
+        Self::new(UTF8::Empty)
+    }
+}
+
+impl From<UTF8> for Utf8Editor {
+    fn from(text: UTF8) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<Utf8Editor> for UTF8 {
+    fn from(editor: Utf8Editor) -> Self {
+        editor.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCENTED: &str = "ae\u{301}b";
+    const FAMILY:   &str = "\u{1f468}\u{200d}\u{1f469}";
+
+    fn edit(text: &str) -> Utf8Editor {
+        Utf8Editor::new(UTF8::from(text))
+    }
+
+    #[test]
+    fn cursor_steps_over_combining_sequences() {
+        let mut editor = edit(ACCENTED);
+        editor.move_home();
+
+        let mut stops = vec![editor.cursor()];
+        for _ in 0..4 {
+            editor.move_right();
+            stops.push(editor.cursor());
+        }
+        assert_eq!(stops, [0, 1, 4, 5, 5]);
+
+        editor.move_left();
+        editor.move_left();
+        assert_eq!(editor.cursor(), 1);
+    }
+
+    #[test]
+    fn cursor_steps_over_zwj_sequences() {
+        let text       = format!("x{FAMILY}y");
+        let mut editor = edit(&text);
+
+        editor.move_left();
+        editor.move_left();
+        assert_eq!(editor.cursor(), 1);
+
+        editor.move_right();
+        assert_eq!(editor.cursor(), 1 + FAMILY.len());
+    }
+
+    #[test]
+    fn backspace_and_delete_remove_whole_clusters() {
+        let mut editor = edit(ACCENTED);
+        editor.set_cursor(4);
+        assert!(editor.backspace());
+        assert_eq!((editor.as_str(), editor.cursor()), ("ab", 1));
+
+        let text       = format!("{FAMILY}!");
+        let mut editor = edit(&text);
+        editor.move_home();
+        assert!(editor.delete());
+        assert_eq!((editor.as_str(), editor.cursor()), ("!", 0));
+
+        editor.move_home();
+        assert!(!editor.backspace());
+    }
+
+    #[test]
+    fn insert_merges_a_combining_mark_into_the_previous_cluster() {
+        let mut editor = edit("eb");
+        editor.set_cursor(1);
+        editor.insert("\u{301}");
+
+        assert_eq!((editor.as_str(), editor.cursor()), ("e\u{301}b", 3));
+        editor.move_left();
+        assert_eq!(editor.cursor(), 0);
+    }
+
+    #[test]
+    fn select_snaps_ends_inside_clusters() {
+        let mut editor = edit(ACCENTED);
+
+        // Byte 2 falls between the `e` and its accent, byte 3 inside the accent; both snap back to the cluster at 1.
+        editor.select(2..3);
+        assert_eq!(editor.selection(), None);
+
+        editor.select(0..3);
+        assert_eq!((editor.selection(), editor.selected()), (Some(0..1), "a"));
+
+        let text       = format!("{FAMILY}z");
+        let mut editor = edit(&text);
+        editor.select(5..FAMILY.len() + 1);
+        assert_eq!(editor.selected(), &text[..]);
+        editor.select(5..6);
+        assert_eq!(editor.selection(), None);
+    }
+
+    #[test]
+    fn replace_selection_swaps_the_selected_text() {
+        let mut editor = edit(ACCENTED);
+        editor.select(1..4);
+        editor.replace_selection("\u{f6}\u{f6}");
+
+        assert_eq!((editor.as_str(), editor.cursor(), editor.selection()), ("a\u{f6}\u{f6}b", 5, None));
+
+        editor.replace_selection("!");
+        assert_eq!((editor.as_str(), editor.cursor()), ("a\u{f6}\u{f6}!b", 6));
+    }
+}
@@ -0,0 +1,92 @@
+//! `Equivalent` lets hashbrown and indexmap maps keyed by `UTF8` be queried by `&str` or `&String`
+//! without building a temporary key. The `Hash` impl on `Str` matches `str` byte for byte, which is
+//! what makes this sound.
+//!
+//! A query matches exactly the keys that `UTF8`'s own `Eq` would, since both compare the text, as
+//! `Equivalent` requires.
+
+use equivalent::Equivalent;
+
+use crate::UTF8;
+
+impl Equivalent<UTF8> for str {
+    fn equivalent(&self, key: &UTF8) -> bool {
+        self.as_bytes() == key.elements()
+    }
+}
+
+impl Equivalent<UTF8> for String {
+    fn equivalent(&self, key: &UTF8) -> bool {
+        self.as_str().equivalent(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{BuildHasher, RandomState};
+
+    use crate::UTF8;
+
+    /// One length per bucket that `From<&str>` picks, from `Empty` to `Boxed`.
+    const LENGTHS: [usize; 7] = [0, 5, 12, 40, 100, 200, 300];
+
+    fn keys() -> impl Iterator<Item = (String, UTF8)> {
+        let built = LENGTHS.into_iter().map(|len| {
+            let text: String = "hello, world ".chars().cycle().take(len).collect();
+            let key = UTF8::from(text.as_str());
+            (text, key)
+        });
+
+        // `From` skips `B32`, so that one is laid out by hand.
+        let mut buf = [0; 32];
+        buf[..20].copy_from_slice(b"twenty bytes of text");
+        built.chain([("twenty bytes of text".to_string(), UTF8::B32 { buf, len: 20 })])
+    }
+
+    #[test]
+    fn keys_cover_every_bucket() {
+        let buckets: Vec<_> = keys().map(|(_, key)| std::mem::discriminant(&key)).collect();
+        for (i, bucket) in buckets.iter().enumerate() {
+            assert!(!buckets[..i].contains(bucket));
+        }
+    }
+
+    #[test]
+    fn hash_matches_str_in_every_bucket() {
+        let state = RandomState::new();
+        for (text, key) in keys() {
+            assert_eq!(state.hash_one(&key), state.hash_one(text.as_str()), "{} bytes", text.len());
+        }
+    }
+
+    #[test]
+    fn hashbrown_lookup_by_str() {
+        let map: hashbrown::HashMap<UTF8, usize> = keys().map(|(text, key)| (key, text.len())).collect();
+        for (text, _) in keys() {
+            assert_eq!(map.get(text.as_str()), Some(&text.len()));
+            assert_eq!(map.get(&text), Some(&text.len()));
+        }
+
+        assert_eq!(map.get("missing"), None);
+    }
+
+    #[test]
+    fn indexmap_lookup_by_str() {
+        let map: indexmap::IndexMap<UTF8, usize> = keys().map(|(text, key)| (key, text.len())).collect();
+        for (i, (text, _)) in keys().enumerate() {
+            assert_eq!(map.get_index_of(text.as_str()), Some(i));
+            assert_eq!(map.get(&text), Some(&text.len()));
+        }
+
+        assert_eq!(map.get("missing"), None);
+    }
+
+    #[test]
+    fn equivalent_agrees_with_eq() {
+        for (text, key) in keys() {
+            assert_eq!(key, UTF8::from(text.clone()));
+            assert!(equivalent::Equivalent::equivalent(text.as_str(), &key));
+            assert!(!equivalent::Equivalent::equivalent("other", &key));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::TryReserveError;
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::UTF8;
+
+/// Errors returned by the crate's fallible constructors and conversions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The value needs more room than the requested storage has.
+    Capacity { len: usize, capacity: usize },
+    /// The bytes are not valid UTF-8.
+    Utf8(std::str::Utf8Error),
+    /// Growing the heap buffer failed.
+    Allocation(TryReserveError),
+    /// The value cannot be represented in the target type.
+    Conversion(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity { len, capacity } => write!(f, "{len} elements do not fit in a capacity of {capacity}"),
+            Self::Utf8(error)       => fmt::Display::fmt(error, f),
+            Self::Allocation(error) => fmt::Display::fmt(error, f),
+            Self::Conversion(what)  => f.write_str(what),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Utf8(error)       => Some(error),
+            Self::Allocation(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::str::Utf8Error> for Error {
+    fn from(error: std::str::Utf8Error) -> Self {
+        Self::Utf8(error)
+    }
+}
+
+impl From<std::string::FromUtf8Error> for Error {
+    fn from(error: std::string::FromUtf8Error) -> Self {
+        Self::Utf8(error.utf8_error())
+    }
+}
+
+impl From<TryReserveError> for Error {
+    fn from(error: TryReserveError) -> Self {
+        Self::Allocation(error)
+    }
+}
+
+impl From<std::char::DecodeUtf16Error> for Error {
+    fn from(_: std::char::DecodeUtf16Error) -> Self {
+        Self::Conversion("unpaired surrogate in UTF-16 input")
+    }
+}
+
+/// A plain error message stored in a `UTF8`, the counterpart of the `String`-backed error that
+/// `Box<dyn Error>::from(String)` builds. Short messages stay inline.
+///
+/// It implements `std::error::Error` and `Display`, so it can be returned as-is, wrapped by `thiserror` (`#[from]`,
+/// `#[error("{0}")]`) or turned into an `anyhow::Error`.
+#[derive(Clone)]
+pub struct ErrorMessage(pub UTF8);
+
+impl ErrorMessage {
+    /// Returns the message text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ErrorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for ErrorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl StdError for ErrorMessage { }
+
+impl From<UTF8> for ErrorMessage {
+    fn from(value: UTF8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for ErrorMessage {
+    fn from(value: &str) -> Self {
+        Self(UTF8::from(value))
+    }
+}
+
+impl From<UTF8> for Box<dyn StdError + Send + Sync> {
+    fn from(value: UTF8) -> Self {
+        Box::new(ErrorMessage(value))
+    }
+}
+
+impl From<UTF8> for Box<dyn StdError> {
+    fn from(value: UTF8) -> Self {
+        Box::new(ErrorMessage(value))
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl From<UTF8> for anyhow::Error {
+    fn from(value: UTF8) -> Self {
+        anyhow::Error::new(ErrorMessage(value))
+    }
+}
@@ -0,0 +1,141 @@
+//! Stable C ABI (`feature = "ffi"`).
+//!
+//! C and C++ callers never see the `Str` enum: they hold an opaque `KaffStr *` created by
+//! [`kaff_str_new`] or [`UTF8::into_ffi`] and released by [`kaff_str_free`], and read it through a borrowed
+//! [`KaffStrView`]. Every type and function here is `#[repr(C)]` / `extern "C"`, so running
+//! `cbindgen` over the crate produces a usable header.
+
+use crate::UTF8;
+
+/// A borrowed, non-owning view of UTF-8 bytes. Never null; empty views point at a static empty buffer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct KaffStrView {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+/// An owned, heap-allocated UTF-8 string handed across the C boundary.
+#[repr(C)]
+pub struct KaffStr {
+    ptr: *mut u8,
+    len: usize,
+    cap: usize,
+}
+
+// `KaffStr` uniquely owns its buffer, like `Box<[u8]>`.
+unsafe impl Send for KaffStr { }
+unsafe impl Sync for KaffStr { }
+
+impl UTF8 {
+    /// Hands the string to foreign code as an owned `KaffStr *`. A `Boxed` buffer is transferred
+    /// without copying; inline values are moved to the heap first. Release it with [`kaff_str_free`]
+    /// or take it back with [`from_ffi`](Self::from_ffi).
+    pub fn into_ffi(self) -> *mut KaffStr {
+        Box::into_raw(Box::new(KaffStr::from(self)))
+    }
+
+    /// Reclaims a handle produced by [`into_ffi`](Self::into_ffi) or [`kaff_str_new`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a live, non-null `KaffStr` pointer that is not used again afterwards.
+    pub unsafe fn from_ffi(ptr: *mut KaffStr) -> Self {
+        Self::from(*Box::from_raw(ptr))
+    }
+
+    /// Borrows the stored bytes as a [`KaffStrView`], valid for as long as `self` is neither moved nor dropped.
+    pub fn as_view(&self) -> KaffStrView {
+        if self.is_empty() {
+            return KaffStrView { ptr: "".as_ptr(), len: 0 };
+        }
+
+        KaffStrView { ptr: self.as_ptr(), len: self.len() }
+    }
+}
+
+impl KaffStr {
+    /// Borrows the owned bytes as a [`KaffStrView`].
+    pub fn as_view(&self) -> KaffStrView {
+        if self.len == 0 {
+            return KaffStrView { ptr: "".as_ptr(), len: 0 };
+        }
+
+        KaffStrView { ptr: self.ptr, len: self.len }
+    }
+}
+
+impl From<UTF8> for KaffStr {
+    /// Takes over a `Boxed` buffer as-is; inline values are copied to the heap.
+    fn from(value: UTF8) -> Self {
+        let (buf, len) = match value {
+            UTF8::Boxed { buf, len } => (buf, len),
+            UTF8::Empty => (Box::default(), 0),
+            _ => (Box::from(value.as_bytes()), value.len()),
+        };
+
+        let cap = buf.len();
+        let ptr = Box::into_raw(buf) as *mut u8;
+        Self { ptr, len, cap }
+    }
+}
+
+impl From<KaffStr> for UTF8 {
+    /// Moves the heap buffer back into a `Boxed` value, or into an inline bucket when it fits.
+    fn from(value: KaffStr) -> Self {
+        let value = std::mem::ManuallyDrop::new(value);
+        let buf   = unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(value.ptr, value.cap)) };
+
+        match value.len {
+            0       => UTF8::Empty,
+            1..=256 => UTF8::from(&buf[..value.len]),
+            len     => UTF8::Boxed { buf, len }
+        }
+    }
+}
+
+impl Drop for KaffStr {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(self.ptr, self.cap)) });
+    }
+}
+
+/// Copies `len` bytes from `ptr` into a new string. Returns null if the bytes are not valid UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes, or may be null when `len` is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kaff_str_new(ptr: *const u8, len: usize) -> *mut KaffStr {
+    let bytes = if len == 0 { &[][..] } else { std::slice::from_raw_parts(ptr, len) };
+
+    match std::str::from_utf8(bytes) {
+        Ok(s)  => UTF8::from(s).into_ffi(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Borrows the bytes of `string`. A null `string` yields an empty view.
+///
+/// # Safety
+///
+/// `string` must be null or a live pointer returned by [`kaff_str_new`] or [`UTF8::into_ffi`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kaff_str_view(string: *const KaffStr) -> KaffStrView {
+    match string.as_ref() {
+        Some(string) => string.as_view(),
+        None => KaffStrView { ptr: "".as_ptr(), len: 0 },
+    }
+}
+
+/// Releases a string created by [`kaff_str_new`] or [`UTF8::into_ffi`]. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `string` must be null or a pointer returned by one of those that has not been freed or reclaimed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kaff_str_free(string: *mut KaffStr) {
+    if !string.is_null() {
+        drop(Box::from_raw(string));
+    }
+}
@@ -0,0 +1,159 @@
+//! Thread-local freelist for heap buffers (`feature = "freelist"`).
+//!
+//! Spills past the inline buckets allocate `Boxed` buffers in a few fixed size classes (512 to 4096
+//! elements). When the crate replaces such a buffer while growing or shrinking, or when a value is dropped through
+//! [`Str::recycle`](crate::Str::recycle), the buffer is kept on a per-thread list instead of being freed,
+//! and the next spill of that class takes it back. Each class keeps at most 16 buffers.
+//!
+//! `Str` is a plain enum whose fields callers move out of, so it cannot have a `Drop` impl itself. Wrap a value
+//! in [`Recycled`] to have it recycled when dropped; a bare `Str` dropped the ordinary way is freed as usual.
+//! Only element types one byte wide, such as `u8`, are recycled.
+//!
+//! The module is exempt from `feature = "safe"`: caching a buffer and handing it back converts between
+//! `Box<[E]>` and `Box<[MaybeUninit<u8>]>` through raw pointers. Leave `freelist` off for a build with no `unsafe`.
+
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+
+use crate::Str;
+
+const CLASSES: [usize; 4] = [512, 1024, 2048, 4096];
+const MAX_PER_CLASS: usize = 16;
+
+type Buffer = Box<[MaybeUninit<u8>]>;
+
+thread_local! {
+    static FREELIST: RefCell<[Vec<Buffer>; CLASSES.len()]> = const { RefCell::new([const { Vec::new() }; CLASSES.len()]) };
+}
+
+/// Frees every buffer cached on the current thread.
+pub fn clear() {
+    let _ = FREELIST.try_with(|lists| lists.borrow_mut().iter_mut().for_each(Vec::clear));
+}
+
+/// Returns the number of buffers cached on the current thread.
+pub fn cached() -> usize {
+    FREELIST.try_with(|lists| lists.borrow().iter().map(Vec::len).sum()).unwrap_or(0)
+}
+
+/// Returns the capacity a spill of at least `len` elements is rounded up to, if it falls in a cached class.
+pub(crate) fn size_class<E>(len: usize) -> Option<usize> {
+    if size_of::<E>() != 1 {
+        return None;
+    }
+
+    CLASSES.into_iter().find(|&class| class >= len)
+}
+
+/// Takes a cached buffer of at least `len` elements, every one set to `E::default()`.
+pub(crate) fn take<E: Copy + Default>(len: usize) -> Option<Box<[E]>> {
+    let class = size_class::<E>(len)?;
+    let index = CLASSES.iter().position(|&c| c == class)?;
+    let buf   = FREELIST.try_with(|lists| lists.borrow_mut()[index].pop()).ok()??;
+
+    let len = buf.len();
+    let ptr = Box::into_raw(buf) as *mut E;
+
+    // `E` is one byte wide, so the allocation has the layout of `[E; len]`; every element is written
+    // before the box is rebuilt.
+    unsafe {
+        for i in 0..len {
+            ptr.add(i).write(E::default());
+        }
+
+        Some(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)))
+    }
+}
+
+/// Keeps `buf` for a later spill if it has a cached size class and that class has room; frees it otherwise.
+pub(crate) fn give<E>(buf: Box<[E]>) {
+    if size_of::<E>() != 1 {
+        return;
+    }
+
+    let Some(index) = CLASSES.iter().position(|&class| class == buf.len()) else { return };
+
+    let len = buf.len();
+    let buf = unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(Box::into_raw(buf) as *mut MaybeUninit<u8>, len)) };
+
+    let _ = FREELIST.try_with(|lists| {
+        let list = &mut lists.borrow_mut()[index];
+        if list.len() < MAX_PER_CLASS {
+            list.push(buf);
+        }
+    });
+}
+
+/// An owned `Str` that goes through [`Str::recycle`] when dropped, so its heap buffer can return to this
+/// thread's freelist. It derefs to the wrapped value; [`into_inner`](Self::into_inner) takes it back out
+/// without recycling.
+#[derive(Clone)]
+pub struct Recycled<E = u8>(Str<E>);
+
+impl<E> Recycled<E> {
+    /// Wraps `value`.
+    pub fn new(value: Str<E>) -> Self {
+        Self(value)
+    }
+
+    /// Unwraps the value, which is then dropped the ordinary way unless recycled by hand.
+    pub fn into_inner(mut self) -> Str<E> {
+        std::mem::replace(&mut self.0, Str::Empty)
+    }
+}
+
+impl<E> Drop for Recycled<E> {
+    fn drop(&mut self) {
+        std::mem::replace(&mut self.0, Str::Empty).recycle();
+    }
+}
+
+impl<E> std::ops::Deref for Recycled<E> {
+    type Target = Str<E>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E> std::ops::DerefMut for Recycled<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<E> std::fmt::Debug for Recycled<E> where Str<E>: std::fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<E> From<Str<E>> for Recycled<E> {
+    fn from(value: Str<E>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UTF8;
+
+    #[test]
+    fn dropping_recycled_caches_the_buffer() {
+        clear();
+
+        let mut value = Recycled::new(UTF8::Empty);
+        value.push_str(&"a".repeat(300));
+        assert!(matches!(*value, Str::Boxed { .. }));
+
+        drop(value);
+        assert_eq!(cached(), 1);
+
+        let mut value = Recycled::new(UTF8::Empty);
+        value.push_str(&"a".repeat(300));
+        assert_eq!(cached(), 0);
+
+        drop(value.into_inner());
+        assert_eq!(cached(), 0);
+    }
+}
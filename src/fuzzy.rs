@@ -0,0 +1,115 @@
+//! Edit distances (`feature = "fuzzy"`).
+//!
+//! Distances count `char`s, not bytes. Strings of up to 64 characters are decoded and compared in stack
+//! scratch space; longer ones fall back to the heap.
+
+use crate::UTF8;
+
+const STACK: usize = 64;
+
+/// Three rows of `STACK + 1` cells, enough for the optimal string alignment table.
+const SCRATCH: usize = 3 * (STACK + 1);
+
+impl UTF8 {
+    /// Returns the Levenshtein distance to `other`: the fewest single-character insertions, deletions and
+    /// substitutions that turn one into the other.
+    pub fn levenshtein(&self, other: &str) -> usize {
+        with_chars(self.text(), |a| with_chars(other, |b| levenshtein(a, b)))
+    }
+
+    /// Like [`levenshtein`](Self::levenshtein), also counting a swap of two adjacent characters as one edit.
+    /// This is the optimal string alignment variant, in which no substring is edited more than once.
+    pub fn damerau_levenshtein(&self, other: &str) -> usize {
+        with_chars(self.text(), |a| with_chars(other, |b| damerau_levenshtein(a, b)))
+    }
+
+    /// Returns a similarity between `0.0` and `1.0`: one minus the Levenshtein distance divided by the longer
+    /// length in characters. Two empty strings are fully similar.
+    pub fn similarity(&self, other: &str) -> f64 {
+        with_chars(self.text(), |a| with_chars(other, |b| {
+            let longest = a.len().max(b.len());
+            if longest == 0 { 1.0 } else { 1.0 - levenshtein(a, b) as f64 / longest as f64 }
+        }))
+    }
+}
+
+/// Decodes `text` into a stack buffer when it has at most 64 characters.
+fn with_chars<R>(text: &str, f: impl FnOnce(&[char]) -> R) -> R {
+    let mut buf = ['\0'; STACK];
+    let mut len = 0;
+
+    for c in text.chars() {
+        if len == STACK {
+            return f(&text.chars().collect::<Vec<_>>());
+        }
+
+        buf[len] = c;
+        len += 1;
+    }
+
+    f(&buf[..len])
+}
+
+/// Runs `f` with `n` zeroed cells, on the stack when `n` is small.
+fn with_row<R>(n: usize, f: impl FnOnce(&mut [usize]) -> R) -> R {
+    if n <= SCRATCH {
+        f(&mut [0; SCRATCH][..n])
+    } else {
+        f(&mut vec![0; n])
+    }
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    with_row(b.len() + 1, |row| {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = j;
+        }
+
+        for (i, &ca) in a.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+
+            for (j, &cb) in b.iter().enumerate() {
+                let above = row[j + 1];
+                row[j + 1] = (diagonal + (ca != cb) as usize).min(above + 1).min(row[j] + 1);
+                diagonal = above;
+            }
+        }
+
+        row[b.len()]
+    })
+}
+
+fn damerau_levenshtein(a: &[char], b: &[char]) -> usize {
+    let width = b.len() + 1;
+
+    with_row(3 * width, |rows| {
+        let (prev2, rest) = rows.split_at_mut(width);
+        let (prev, cur)   = rest.split_at_mut(width);
+        let (mut prev2, mut prev, mut cur) = (prev2, prev, cur);
+
+        for (j, cell) in prev.iter_mut().enumerate() {
+            *cell = j;
+        }
+
+        for i in 1..=a.len() {
+            cur[0] = i;
+
+            for j in 1..=b.len() {
+                let cost = (a[i - 1] != b[j - 1]) as usize;
+                let mut best = (prev[j - 1] + cost).min(prev[j] + 1).min(cur[j - 1] + 1);
+
+                if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    best = best.min(prev2[j - 2] + 1);
+                }
+
+                cur[j] = best;
+            }
+
+            std::mem::swap(&mut prev2, &mut prev);
+            std::mem::swap(&mut prev, &mut cur);
+        }
+
+        prev[b.len()]
+    })
+}
@@ -0,0 +1,106 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::OnceLock;
+
+use crate::UTF8;
+
+/// A `UTF8` key that hashes its text once, at construction, and feeds only the cached 64-bit value to
+/// hashers afterwards. Worth it when the same key goes into several maps.
+///
+/// Maps only see the cached value, so their own `RandomState` cannot protect against crafted collisions.
+/// The hash is therefore keyed once per process at random, like a `RandomState`: values are stable within
+/// a run but differ between runs. Like `UTF8`, two `HashedUTF8` values are equal when they hold the same text.
+#[derive(Clone)]
+pub struct HashedUTF8 {
+    value: UTF8,
+    hash:  u64,
+}
+
+impl HashedUTF8 {
+    /// Hashes `value` and wraps it.
+    pub fn new(value: UTF8) -> Self {
+        static KEYS: OnceLock<RandomState> = OnceLock::new();
+        let hash = KEYS.get_or_init(RandomState::new).hash_one(&value);
+        Self { value, hash }
+    }
+
+    /// Returns the cached hash.
+    pub fn hash_value(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the wrapped string.
+    pub fn get(&self) -> &UTF8 {
+        &self.value
+    }
+
+    /// Unwraps the string.
+    pub fn into_inner(self) -> UTF8 {
+        self.value
+    }
+}
+
+impl std::fmt::Debug for HashedUTF8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl std::ops::Deref for HashedUTF8 {
+    type Target = UTF8;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl PartialEq for HashedUTF8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.value.elements() == other.value.elements()
+    }
+}
+impl Eq for HashedUTF8 { }
+
+impl Hash for HashedUTF8 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl From<UTF8> for HashedUTF8 {
+    fn from(value: UTF8) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for HashedUTF8 {
+    fn from(value: &str) -> Self {
+        Self::new(UTF8::from(value))
+    }
+}
+
+impl From<HashedUTF8> for UTF8 {
+    fn from(value: HashedUTF8) -> Self {
+        value.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_text_hashes_the_same() {
+        let (a, b) = (HashedUTF8::from("key"), HashedUTF8::new(UTF8::from(String::from("key"))));
+        assert_eq!(a.hash_value(), b.hash_value());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn hash_is_not_the_fixed_key_default() {
+        use std::hash::{BuildHasherDefault, DefaultHasher};
+
+        let value = UTF8::from("key");
+        let fixed = BuildHasherDefault::<DefaultHasher>::default().hash_one(&value);
+        assert_ne!(HashedUTF8::new(value).hash_value(), fixed);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::{Blob, Str};
+
+/// A `Debug`/`Display` adapter printing bytes as a hex dump, in the layout of `hexdump -C`: an offset,
+/// 16 bytes in hex split into two groups of 8, and a gutter with printable ASCII and `.` for the rest.
+#[derive(Clone, Copy)]
+pub struct HexDump<'a>(&'a [u8]);
+
+impl Str<u8> {
+    /// Returns a hex dump of the stored bytes, for logs and test failures.
+    pub fn hex_dump(&self) -> HexDump<'_> {
+        HexDump(self.elements())
+    }
+}
+
+impl Blob {
+    /// Returns a hex dump of the stored bytes.
+    pub fn hex_dump(&self) -> HexDump<'_> {
+        self.0.hex_dump()
+    }
+}
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row, line) in self.0.chunks(16).enumerate() {
+            if row > 0 {
+                f.write_str("\n")?;
+            }
+
+            write!(f, "{:08x} ", row * 16)?;
+
+            for i in 0..16 {
+                if i % 8 == 0 {
+                    f.write_str(" ")?;
+                }
+
+                match line.get(i) {
+                    Some(byte) => write!(f, "{byte:02x} ")?,
+                    None => f.write_str("   ")?,
+                }
+            }
+
+            f.write_str(" |")?;
+            for &byte in line {
+                let c = if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' };
+                write!(f, "{c}")?;
+            }
+            f.write_str("|")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,44 @@
+use http::header::{HeaderName, HeaderValue, InvalidHeaderName, InvalidHeaderValue, ToStrError};
+use crate::UTF8;
+
+impl TryFrom<&HeaderValue> for UTF8 {
+    type Error = ToStrError;
+
+    /// Copies a visible-ASCII header value straight into a `UTF8`, with the same rules as `HeaderValue::to_str`.
+    fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+        value.to_str().map(UTF8::from)
+    }
+}
+
+impl TryFrom<&UTF8> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    fn try_from(value: &UTF8) -> Result<Self, Self::Error> {
+        HeaderValue::from_bytes(value.as_bytes())
+    }
+}
+
+impl TryFrom<UTF8> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    /// Like the `&UTF8` conversion, but a `Boxed` buffer becomes the header's storage without copying.
+    fn try_from(value: UTF8) -> Result<Self, Self::Error> {
+        HeaderValue::try_from(String::from(value))
+    }
+}
+
+impl From<&HeaderName> for UTF8 {
+    /// Copies the lowercase name; every standard header fits an inline bucket.
+    fn from(name: &HeaderName) -> Self {
+        UTF8::from(name.as_str())
+    }
+}
+
+impl TryFrom<&UTF8> for HeaderName {
+    type Error = InvalidHeaderName;
+
+    /// Parses a header name, lowercasing it and resolving standard names to their static constants.
+    fn try_from(value: &UTF8) -> Result<Self, Self::Error> {
+        HeaderName::from_bytes(value.as_bytes())
+    }
+}
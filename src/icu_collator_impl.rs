@@ -0,0 +1,18 @@
+use icu_collator::CollatorBorrowed;
+
+use crate::{Blob, Str, UTF8};
+
+impl UTF8 {
+    /// Writes the ICU4X sort key of the text under `collator`, whose `memcmp` order matches
+    /// `collator.compare`. Short keys are copied inline; longer ones keep the buffer they were written to.
+    /// Keys depend on the CLDR and ICU4X versions, so stored keys must be rebuilt when either changes.
+    pub fn to_icu_sort_key(&self, collator: &CollatorBorrowed<'_>) -> Blob {
+        let mut key = Vec::new();
+        let Ok(()) = collator.write_sort_key_to(self, &mut key);
+
+        match key.len() {
+            0..=256 => Blob::from(&key[..]),
+            len     => Blob(Str::Boxed { buf: key.into_boxed_slice(), len }),
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! `std::io` adapters.
+//!
+//! `UTF8` is the same type as `Str<u8>`, so raw bytes are written through [`Blob`], while
+//! [`Utf8Writer`] checks that what it collects stays valid UTF-8. [`Utf8Cursor`] reads either back.
+//! [`read_line_utf8`] and [`read_until_into`] read from a `BufRead`, and `from_reader` on [`UTF8`] and
+//! [`Blob`] reads a whole `Read`, without an intermediate `String` or `Vec`.
+
+use std::borrow::Borrow;
+use std::io;
+
+use crate::{Blob, Str, UTF8};
+
+#[cfg(feature = "tokio")]
+pub use crate::tokio_impl::{AsyncBufReadUtf8Ext, AsyncReadUtf8Ext, AsyncWriteUtf8Ext};
+
+#[cfg(feature = "tokio-util")]
+pub use crate::tokio_util_impl::Utf8Lines;
+
+/// Appends the written bytes, staying inline until 256 bytes and growing a heap buffer after that.
+impl io::Write for Blob {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.0.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An `io::Write` sink that collects UTF-8 into a [`UTF8`].
+///
+/// Sequences split across writes are held back until they complete. A strict writer ([`new`](Self::new))
+/// fails with `InvalidData` on malformed input; a [`lossy`](Self::lossy) one writes `U+FFFD` in its place.
+pub struct Utf8Writer {
+    buf:     UTF8,
+    pending: [u8; 4],
+    partial: usize,
+    lossy:   bool,
+}
+
+impl Utf8Writer {
+    /// Creates a writer that rejects invalid UTF-8.
+    pub fn new() -> Self {
+        Self { buf: Str::Empty, pending: [0; 4], partial: 0, lossy: false }
+    }
+
+    /// Creates a writer that replaces invalid UTF-8 with `U+FFFD`.
+    pub fn lossy() -> Self {
+        Self { lossy: true, ..Self::new() }
+    }
+
+    /// Returns the text written so far, excluding a trailing sequence that has not completed yet.
+    pub fn as_str(&self) -> &str {
+        &self.buf
+    }
+
+    /// Returns the collected string. A sequence still cut off at the end is an error for a strict writer
+    /// and becomes `U+FFFD` for a lossy one.
+    pub fn finish(mut self) -> io::Result<UTF8> {
+        if self.partial > 0 {
+            self.invalid()?;
+        }
+
+        debug_assert_eq!(self.buf.check_utf8(), Ok(()));
+        Ok(self.buf)
+    }
+
+    fn invalid(&mut self) -> io::Result<()> {
+        if !self.lossy {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8"));
+        }
+
+        self.buf.extend_from_slice("\u{FFFD}".as_bytes());
+        self.partial = 0;
+        Ok(())
+    }
+}
+
+impl Default for Utf8Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl io::Write for Utf8Writer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut rest = buf;
+
+        // Complete a sequence left over from the previous write one byte at a time.
+        while self.partial > 0 && !rest.is_empty() {
+            self.pending[self.partial] = rest[0];
+
+            match std::str::from_utf8(&self.pending[..=self.partial]) {
+                Ok(c) => {
+                    self.buf.extend_from_slice(c.as_bytes());
+                    self.partial = 0;
+                    rest = &rest[1..];
+                }
+
+                Err(e) if e.error_len().is_none() => {
+                    self.partial += 1;
+                    rest = &rest[1..];
+                }
+
+                Err(_) if !self.lossy && rest.len() < buf.len() => return Ok(buf.len() - rest.len()),
+
+                // The held-back bytes are invalid; the new byte is looked at again below.
+                Err(_) => self.invalid()?,
+            }
+        }
+
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    self.buf.extend_from_slice(valid.as_bytes());
+                    return Ok(buf.len());
+                }
+
+                Err(e) => {
+                    let (valid, tail) = rest.split_at(e.valid_up_to());
+                    self.buf.extend_from_slice(valid);
+
+                    let Some(bad) = e.error_len() else {
+                        self.pending[..tail.len()].copy_from_slice(tail);
+                        self.partial = tail.len();
+                        return Ok(buf.len());
+                    };
+
+                    if !self.lossy {
+                        let written = buf.len() - tail.len();
+                        return if written > 0 { Ok(written) } else { self.invalid().map(|_| 0) };
+                    }
+
+                    self.invalid()?;
+                    rest = &tail[bad..];
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A `Read` + `BufRead` + `Seek` cursor over a `Str<u8>`, owned or borrowed, reading in place.
+///
+/// Works like `io::Cursor`, but takes the buffer directly instead of a `&[u8]` or `Vec<u8>`.
+pub struct Utf8Cursor<S> {
+    inner: S,
+    pos:   u64,
+}
+
+impl<S: Borrow<Str<u8>>> Utf8Cursor<S> {
+    /// Creates a cursor positioned at the start of `inner`.
+    pub fn new(inner: S) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Returns the current position in bytes.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Moves the cursor to `pos`; positions past the end read nothing.
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    /// Returns the underlying buffer.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consumes the cursor, returning the underlying buffer.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    pub(crate) fn remaining_bytes(&self) -> &[u8] {
+        let bytes = self.inner.borrow().elements();
+        &bytes[self.pos.min(bytes.len() as u64) as usize..]
+    }
+}
+
+impl<S: Borrow<Str<u8>>> io::Read for Utf8Cursor<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let rest = self.remaining_bytes();
+        let n    = rest.len().min(buf.len());
+        buf[..n].copy_from_slice(&rest[..n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<S: Borrow<Str<u8>>> io::BufRead for Utf8Cursor<S> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_bytes())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+impl<S: Borrow<Str<u8>>> io::Seek for Utf8Cursor<S> {
+    fn seek(&mut self, style: io::SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            io::SeekFrom::Start(n)   => { self.pos = n; return Ok(n) }
+            io::SeekFrom::End(n)     => (self.inner.borrow().len() as u64, n),
+            io::SeekFrom::Current(n) => (self.pos, n),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(pos) => { self.pos = pos; Ok(pos) }
+            None => Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid seek to a negative or overflowing position")),
+        }
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+impl Blob {
+    /// Reads `reader` to the end. Input that fits in 256 bytes is gathered on the stack and lands in its
+    /// bucket with a single copy; longer input continues into a heap buffer that is kept as-is.
+    pub fn from_reader<R: io::Read>(mut reader: R) -> io::Result<Self> {
+        let mut buf = [0u8; 256];
+        let mut len = 0;
+
+        while len < buf.len() {
+            match reader.read(&mut buf[len..]) {
+                Ok(0)  => return Ok(Blob::from(&buf[..len])),
+                Ok(n)  => len += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut vec = Vec::from(buf);
+        reader.read_to_end(&mut vec)?;
+
+        if vec.len() == buf.len() {
+            return Ok(Blob::from(&buf[..]));
+        }
+
+        let len = vec.len();
+        vec.resize(vec.capacity(), 0);
+
+        let mut value = Str::Boxed { len: vec.len(), buf: vec.into_boxed_slice() };
+        value.set_len(len);
+        Ok(Blob(value))
+    }
+}
+
+impl UTF8 {
+    /// Like [`Blob::from_reader`], then checks the bytes, failing with `InvalidData` if they are not valid UTF-8.
+    pub fn from_reader<R: io::Read>(reader: R) -> io::Result<Self> {
+        UTF8::from_bytes(Blob::from_reader(reader)?)
+            .map_err(|(error, _)| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+/// Reads one line, including its `\n` if present, straight into a `UTF8`. Returns `Empty` at end of input.
+///
+/// Unlike `BufRead::read_line`, no `String` is involved, so short lines never touch the heap.
+/// A line that is not valid UTF-8 fails with `InvalidData`; its bytes are consumed either way.
+pub fn read_line_utf8<R: io::BufRead + ?Sized>(reader: &mut R) -> io::Result<UTF8> {
+    let mut line = Blob::default();
+    read_until_into(reader, b'\n', &mut line)?;
+
+    match std::str::from_utf8(line.as_bytes()) {
+        Ok(_)  => Ok(line.0),
+        Err(_) => Err(io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")),
+    }
+}
+
+/// Like `BufRead::read_until`, appending to a [`Blob`] instead of a `Vec<u8>`. Returns the number of
+/// bytes read, including `delim` if it was found.
+///
+/// The bytes are not validated, which is why this takes a `Blob` rather than a bare `Str<u8>`.
+pub fn read_until_into<R: io::BufRead + ?Sized>(reader: &mut R, delim: u8, buf: &mut Blob) -> io::Result<usize> {
+    let mut read = 0;
+
+    loop {
+        let (done, used) = {
+            let available = match reader.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            match available.iter().position(|&b| b == delim) {
+                Some(i) => {
+                    buf.0.extend_from_slice(&available[..=i]);
+                    (true, i + 1)
+                }
+
+                None => {
+                    buf.0.extend_from_slice(available);
+                    (available.is_empty(), available.len())
+                }
+            }
+        };
+
+        reader.consume(used);
+        read += used;
+
+        if done {
+            return Ok(read);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn sequences_split_across_writes_are_joined() {
+        let mut writer = Utf8Writer::new();
+        writer.write_all(b"h\xc3").unwrap();
+        assert_eq!(writer.as_str(), "h");
+
+        writer.write_all(b"\xa9llo \xf0\x9f").unwrap();
+        writer.write_all(b"\x98").unwrap();
+        writer.write_all(b"\x80").unwrap();
+        assert_eq!(&*writer.finish().unwrap(), "h\u{e9}llo \u{1f600}");
+    }
+
+    #[test]
+    fn strict_writers_reject_invalid_bytes() {
+        let mut writer = Utf8Writer::new();
+        assert_eq!(writer.write(b"ab\xffcd").unwrap(), 2);
+        assert_eq!(writer.write(b"\xffcd").unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(writer.as_str(), "ab");
+
+        let mut writer = Utf8Writer::new();
+        writer.write_all(b"\xc3").unwrap();
+        assert_eq!(writer.write_all(b"A").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn lossy_writers_replace_invalid_bytes() {
+        let mut writer = Utf8Writer::lossy();
+        writer.write_all(b"ab\xffcd\xc3").unwrap();
+        writer.write_all(b"A").unwrap();
+        assert_eq!(&*writer.finish().unwrap(), "ab\u{fffd}cd\u{fffd}A");
+    }
+
+    #[test]
+    fn a_truncated_trailing_sequence_is_held_until_finish() {
+        let mut writer = Utf8Writer::new();
+        writer.write_all(b"ok\xe2\x82").unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.as_str(), "ok");
+        assert_eq!(writer.finish().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut writer = Utf8Writer::lossy();
+        writer.write_all(b"ok\xe2\x82").unwrap();
+        writer.flush().unwrap();
+        assert_eq!(&*writer.finish().unwrap(), "ok\u{fffd}");
+    }
+}
@@ -0,0 +1,14 @@
+use crate::UTF8;
+
+impl UTF8 {
+    /// Formats an integer with `itoa`. Every integer type fits in a `B8`, `B16` or `B64` bucket.
+    pub fn from_int<I: itoa::Integer>(value: I) -> Self {
+        Self::from(itoa::Buffer::new().format(value))
+    }
+
+    /// Appends an integer formatted with `itoa`.
+    pub fn push_int<I: itoa::Integer>(&mut self, value: I) {
+        self.extend_from_slice(itoa::Buffer::new().format(value).as_bytes());
+        debug_assert_eq!(self.check_utf8(), Ok(()));
+    }
+}
@@ -0,0 +1,22 @@
+use std::borrow::Cow;
+use jni::{Env, errors::Result, objects::JString};
+use crate::UTF8;
+
+impl UTF8 {
+    /// Reads a Java string through `GetStringUTFChars`. Modified UTF-8 that is already valid UTF-8
+    /// (no embedded NULs, no supplementary characters) is copied straight into an inline bucket;
+    /// anything else is transcoded first.
+    pub fn from_jstring(env: &Env<'_>, string: &JString<'_>) -> Result<Self> {
+        let chars = string.mutf8_chars(env)?;
+
+        Ok(match chars.to_str() {
+            Cow::Borrowed(s) => Self::from(s),
+            Cow::Owned(s)    => Self::from(s),
+        })
+    }
+
+    /// Creates a Java string from the stored bytes, encoding them as modified UTF-8.
+    pub fn to_jstring<'local>(&self, env: &mut Env<'local>) -> Result<JString<'local>> {
+        env.new_string(self)
+    }
+}
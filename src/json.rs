@@ -0,0 +1,46 @@
+//! Interop with `serde_json` trees (`feature = "serde_json"`).
+
+use serde::de::{Error as _, Unexpected};
+use serde_json::{Error, Map, Value};
+use crate::UTF8;
+
+impl From<UTF8> for Value {
+    fn from(value: UTF8) -> Self {
+        Value::String(value.into())
+    }
+}
+
+impl TryFrom<&Value> for UTF8 {
+    type Error = Error;
+
+    /// Copies a JSON string into a `UTF8`; any other value is reported as an `invalid type` error.
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let unexpected = match value {
+            Value::String(s) => return Ok(UTF8::from(s.as_str())),
+            Value::Null      => Unexpected::Unit,
+            Value::Bool(b)   => Unexpected::Bool(*b),
+            Value::Number(_) => Unexpected::Other("number"),
+            Value::Array(_)  => Unexpected::Seq,
+            Value::Object(_) => Unexpected::Map,
+        };
+
+        Err(Error::invalid_type(unexpected, &"a string"))
+    }
+}
+
+/// Iterates over the keys of `map`, each copied straight into a `UTF8`.
+pub fn keys(map: &Map<String, Value>) -> impl Iterator<Item = UTF8> + '_ {
+    map.keys().map(|key| UTF8::from(key.as_str()))
+}
+
+/// Iterates over the entries of `map` whose value is a JSON string, yielding both sides as `UTF8`.
+pub fn string_entries(map: &Map<String, Value>) -> impl Iterator<Item = (UTF8, UTF8)> + '_ {
+    map.iter().filter_map(|(key, value)| {
+        value.as_str().map(|value| (UTF8::from(key.as_str()), UTF8::from(value)))
+    })
+}
+
+/// Returns the value stored under `key` as a `UTF8`, or `None` if it is missing or not a string.
+pub fn get_str(map: &Map<String, Value>, key: &str) -> Option<UTF8> {
+    map.get(key).and_then(Value::as_str).map(UTF8::from)
+}
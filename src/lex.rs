@@ -0,0 +1,129 @@
+//! Tokenizer support.
+//!
+//! [`CharCursor`] walks the text of a `UTF8` one character at a time, tracking the byte offset, the current
+//! line and the start of the token being scanned, so a lexer can be written against the SSO type without
+//! juggling `&str` indices. For reading bytes through `std::io`, see [`io::Utf8Cursor`](crate::io::Utf8Cursor).
+
+use crate::line_index::column;
+use crate::{ColumnMode, LineCol, UTF8};
+
+/// A read-only character cursor over a `UTF8`.
+///
+/// Everything consumed since the last [`take_slice`](Self::take_slice) forms the current token.
+#[derive(Clone, Debug)]
+pub struct CharCursor<'a> {
+    text:       &'a str,
+    pos:        usize,
+    start:      usize,
+    line:       usize,
+    line_start: usize,
+}
+
+impl<'a> CharCursor<'a> {
+    /// Starts at the beginning of `text`.
+    pub fn new(text: &'a UTF8) -> Self {
+        Self { text: text.text(), pos: 0, start: 0, line: 0, line_start: 0 }
+    }
+
+    /// Returns the next character without consuming it.
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Returns the character after the next one without consuming anything.
+    pub fn peek_second(&self) -> Option<char> {
+        self.rest().chars().nth(1)
+    }
+
+    /// Consumes and returns the next character.
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+
+        if c == '\n' {
+            self.line += 1;
+            self.line_start = self.pos;
+        }
+
+        Some(c)
+    }
+
+    /// Consumes the next character if it is `expected`.
+    pub fn eat(&mut self, expected: char) -> bool {
+        let matched = self.peek() == Some(expected);
+        if matched {
+            self.next_char();
+        }
+
+        matched
+    }
+
+    /// Consumes characters while `pred` holds and returns them.
+    pub fn advance_while(&mut self, mut pred: impl FnMut(char) -> bool) -> &'a str {
+        let from = self.pos;
+        while let Some(c) = self.peek() {
+            if !pred(c) {
+                break;
+            }
+            self.next_char();
+        }
+
+        &self.text[from..self.pos]
+    }
+
+    /// Returns the current token, everything consumed since the last call, and starts the next one here.
+    pub fn take_slice(&mut self) -> &'a str {
+        let token = &self.text[self.start..self.pos];
+        self.start = self.pos;
+        token
+    }
+
+    /// Like [`take_slice`](Self::take_slice), copying the token into a `UTF8` that stays inline when it fits.
+    pub fn take_utf8(&mut self) -> UTF8 {
+        UTF8::from(self.take_slice())
+    }
+
+    /// Returns the current token without ending it.
+    pub fn current_slice(&self) -> &'a str {
+        &self.text[self.start..self.pos]
+    }
+
+    /// Returns the text not consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Returns `true` once every character has been consumed.
+    pub fn is_eof(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// Returns the byte offset of the next character.
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the byte offset at which the current token starts.
+    pub fn token_start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the zero-based line and column of the next character, with columns counted as `mode` says.
+    pub fn line_col(&self, mode: ColumnMode) -> LineCol {
+        LineCol { line: self.line, column: column(&self.text[self.line_start..self.pos], mode) }
+    }
+}
+
+impl<'a> From<&'a UTF8> for CharCursor<'a> {
+    fn from(text: &'a UTF8) -> Self {
+        Self::new(text)
+    }
+}
+
+impl Iterator for CharCursor<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.next_char()
+    }
+}
@@ -1,17 +1,19 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+#![cfg_attr(feature = "safe", deny(unsafe_code))]
 
 use std::hash::{Hash, Hasher};
 
 /// A fixed-capacity or heap-allocated buffer storing elements of type `E`.
 ///
 /// Small buffers (up to 256 elements) are stored inline; larger ones use heap allocation.
+#[derive(Clone)]
 pub enum Str<E: Sized> {
     B8    { buf: [E;   8], len: u8    },
     B16   { buf: [E;  16], len: u8    },
     B32   { buf: [E;  32], len: u8    },
     B64   { buf: [E;  64], len: u8    },
     B128  { buf: [E; 128], len: u8    },
-    B256  { buf: [E; 256], len: u8    },
+    B256  { buf: [E; 256], len: u16   },
     Boxed { buf: Box<[E]>, len: usize },
     Empty
 }
@@ -19,8 +21,22 @@ pub enum Str<E: Sized> {
 /// UTF-8 string specialization using a `Str<u8>` buffer.
 pub type UTF8 = Str<u8>;
 
+/// Arbitrary bytes in a `Str<u8>`, mapped to the binary column type by the database integrations and to
+/// `Buffer` by napi.
+///
+/// `UTF8` is an alias of `Str<u8>`, so the wrapper is what tells binary values apart from text.
+#[derive(Clone)]
+pub struct Blob(pub Str<u8>);
+
 impl<E> Str<E> {
-    /// Returns a slice of the stored elements.
+    /// Returns a slice of the stored elements. `Empty` yields an empty slice.
+    ///
+    /// # Safety
+    ///
+    /// `len()` must not exceed the variant's storage, the first `len()` elements must be initialized, and
+    /// no mutable alias may exist while the slice lives. Values built through the crate's constructors
+    /// always satisfy the first two.
+    #[cfg(not(feature = "safe"))]
     pub unsafe fn as_slice(&self) -> &[E] {
         let (ptr, len) = match self {
             Self::B8    { buf, len } => (buf.as_ptr(), *len as _),
@@ -30,13 +46,50 @@ impl<E> Str<E> {
             Self::B128  { buf, len } => (buf.as_ptr(), *len as _),
             Self::B256  { buf, len } => (buf.as_ptr(), *len as _),
             Self::Boxed { buf, len } => (buf.as_ptr(), *len     ),
-            Self::Empty => (std::ptr::null(), 0)
+            Self::Empty => (std::ptr::NonNull::dangling().as_ptr() as *const E, 0)
         };
 
         &*std::ptr::slice_from_raw_parts(ptr, len)
     }
 
+    /// Returns a slice of the stored elements.
+    ///
+    /// # Safety
+    ///
+    /// With `feature = "safe"` this is bounds-checked slicing and has no requirements; it stays `unsafe` so that
+    /// enabling the feature does not change the signature.
+    #[cfg(feature = "safe")]
+    #[allow(unsafe_code)]
+    pub unsafe fn as_slice(&self) -> &[E] {
+        self.elements()
+    }
+
+    /// Returns the stored elements; `Empty` yields an empty slice.
+    #[cfg(not(feature = "safe"))]
+    pub(crate) fn elements(&self) -> &[E] {
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+        unsafe { self.as_slice() }
+    }
+
+    /// Returns the stored elements through bounds-checked slicing.
+    #[cfg(feature = "safe")]
+    pub(crate) fn elements(&self) -> &[E] {
+        match self {
+            Self::B8    { buf, len } => &buf[..*len as usize],
+            Self::B16   { buf, len } => &buf[..*len as usize],
+            Self::B32   { buf, len } => &buf[..*len as usize],
+            Self::B64   { buf, len } => &buf[..*len as usize],
+            Self::B128  { buf, len } => &buf[..*len as usize],
+            Self::B256  { buf, len } => &buf[..*len as usize],
+            Self::Boxed { buf, len } => &buf[..*len],
+            Self::Empty => &[]
+        }
+    }
+
     /// Returns a raw pointer to the buffer.
+    ///
+    /// The pointer is never null. For `Empty` it is dangling but well-aligned, which makes it valid for
+    /// zero-length reads such as `slice::from_raw_parts(ptr, 0)`, though it must not be dereferenced.
     pub fn as_ptr(&self) -> *const E {
         match self {
             Self::B8    { buf, .. } => buf.as_ptr(),
@@ -46,11 +99,17 @@ impl<E> Str<E> {
             Self::B128  { buf, .. } => buf.as_ptr(),
             Self::B256  { buf, .. } => buf.as_ptr(),
             Self::Boxed { buf, .. } => buf.as_ptr(),
-            Self::Empty => std::ptr::null()
+            Self::Empty => std::ptr::NonNull::dangling().as_ptr()
         }
     }
 
     /// Returns an unsafe mutable raw pointer to the buffer.
+    ///
+    /// # Safety
+    ///
+    /// Writes through the pointer must stay within the buffer and must not alias live references.
+    /// Like [`as_ptr`](Self::as_ptr) it is never null, and dangling for `Empty`, which has no room to write to.
+    #[cfg_attr(feature = "safe", allow(unsafe_code))]
     pub unsafe fn as_mut_ptr(&mut self) -> *mut E {
         match self {
             Self::B8    { buf, .. } => buf.as_mut_ptr(),
@@ -60,7 +119,7 @@ impl<E> Str<E> {
             Self::B128  { buf, .. } => buf.as_mut_ptr(),
             Self::B256  { buf, .. } => buf.as_mut_ptr(),
             Self::Boxed { buf, .. } => buf.as_mut_ptr(),
-            Self::Empty => std::ptr::null_mut()
+            Self::Empty => std::ptr::NonNull::dangling().as_ptr()
         }
     }
 
@@ -71,60 +130,361 @@ impl<E> Str<E> {
             Self::B16   { len, .. } |
             Self::B32   { len, .. } |
             Self::B64   { len, .. } |
-            Self::B128  { len, .. } |
+            Self::B128  { len, .. } => *len as _,
             Self::B256  { len, .. } => *len as _,
             Self::Boxed { len, .. } => *len     ,
             Self::Empty => 0
         }
     }
+
+    /// Returns `true` if the buffer holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every element, keeping the bucket or heap buffer for reuse.
+    pub fn clear(&mut self) {
+        self.set_len(0);
+    }
+
+    /// Drops the value. With `feature = "freelist"`, a `Boxed` buffer of one of the cached size classes goes
+    /// back to this thread's freelist, to be reused by the next spill.
+    pub fn recycle(self) {
+        #[cfg(feature = "freelist")]
+        if let Self::Boxed { buf, .. } = self {
+            freelist::give(buf);
+        }
+    }
 }
 
-impl<E> PartialEq for Str<E> {
+impl<E: Copy + Default> Str<E> {
+    /// Appends one element, like [`extend_elements`](Self::extend_elements).
+    ///
+    /// # Safety
+    ///
+    /// On a `UTF8` the text must stay valid UTF-8, as [`push`](Str::push) guarantees and a single byte generally
+    /// does not. Other element types, and bytes wrapped in a [`Blob`], have no requirements; `Blob::push` is the
+    /// safe way to append raw bytes.
+    #[cfg_attr(feature = "safe", allow(unsafe_code))]
+    pub unsafe fn push_element(&mut self, item: E) {
+        self.extend_from_slice(&[item]);
+    }
+
+    /// Removes and returns the last element, keeping the bucket.
+    ///
+    /// # Safety
+    ///
+    /// On a `UTF8` the remaining text must stay valid UTF-8, which removing one byte of a multi-byte character
+    /// breaks; [`pop`](Str::pop) removes a whole character instead. Other element types have no requirements.
+    #[cfg_attr(feature = "safe", allow(unsafe_code))]
+    pub unsafe fn pop_element(&mut self) -> Option<E> {
+        let item = *self.elements().last()?;
+        self.set_len(self.len() - 1);
+        Some(item)
+    }
+
+    /// Appends `items`, growing the buffer first if they do not fit: into the next bucket that holds them,
+    /// then into a `Boxed` buffer that doubles.
+    ///
+    /// # Safety
+    ///
+    /// On a `UTF8` the text must stay valid UTF-8; [`push_str`](Str::push_str) appends text safely. Other
+    /// element types have no requirements.
+    #[cfg_attr(feature = "safe", allow(unsafe_code))]
+    pub unsafe fn extend_elements(&mut self, items: &[E]) {
+        self.extend_from_slice(items);
+    }
+
+    /// Appends `items`, growing the buffer first if they do not fit. Callers appending to a `UTF8` keep it valid.
+    pub(crate) fn extend_from_slice(&mut self, items: &[E]) {
+        if items.is_empty() {
+            return;
+        }
+
+        let len = self.len();
+        self.reserve(items.len());
+        self.spare_mut()[..items.len()].copy_from_slice(items);
+        self.set_len(len + items.len());
+
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+    }
+
+    /// Makes room for `additional` more elements, panicking if the heap buffer cannot grow.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            panic!("{error}");
+        }
+    }
+
+    /// Makes room for `additional` more elements, moving to the smallest bucket that holds them,
+    /// or to a `Boxed` buffer with spare capacity once 256 elements are exceeded.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+        let old = self.len();
+        let new = old.saturating_add(additional);
+
+        if new <= self.capacity() {
+            return Ok(());
+        }
+
+        let mut grown = if new <= 256 {
+            let mut buf = [E::default(); 256];
+            buf[..old].copy_from_slice(self.elements());
+            Self::from(&buf[..new])
+        } else if let Some(mut buf) = freelist::take::<E>(new) {
+            buf[..old].copy_from_slice(self.elements());
+            Self::Boxed { len: buf.len(), buf }
+        } else {
+            let want = new.max(old.saturating_mul(2));
+
+            let mut vec = Vec::new();
+            if vec.try_reserve_exact(freelist::size_class::<E>(want).unwrap_or(want)).is_err() {
+                vec.try_reserve_exact(new)?;
+            }
+
+            vec.extend_from_slice(self.elements());
+            vec.resize(vec.capacity(), E::default());
+            Self::Boxed { len: vec.len(), buf: vec.into_boxed_slice() }
+        };
+
+        grown.set_len(old);
+        std::mem::replace(self, grown).recycle();
+
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+        Ok(())
+    }
+
+    /// Moves an inline value into the smallest bucket that holds it (the one `From<&[E]>` picks), or to
+    /// `Empty` when it has no elements. `Boxed` values are left alone; see [`shrink_to_fit`](Self::shrink_to_fit).
+    pub fn normalize(&mut self) {
+        if !matches!(self, Self::Boxed { .. }) {
+            self.rebucket();
+        }
+    }
+
+    /// Like [`normalize`](Self::normalize), but also moves `Boxed` values of up to 256 elements inline
+    /// and drops the spare capacity of larger ones.
+    pub fn shrink_to_fit(&mut self) {
+        match self {
+            Self::Boxed { buf, len } if *len > 256 => {
+                if buf.len() > *len {
+                    let mut vec = Vec::from(std::mem::take(buf));
+                    vec.truncate(*len);
+                    *buf = vec.into_boxed_slice();
+                }
+            }
+
+            _ => self.rebucket(),
+        }
+    }
+
+    fn rebucket(&mut self) {
+        let tight = Self::from(self.elements());
+        if std::mem::discriminant(&tight) != std::mem::discriminant(self) {
+            std::mem::replace(self, tight).recycle();
+        }
+    }
+
+    /// Copies `slice` into an inline bucket, failing instead of moving to the heap when it holds more than 256 elements.
+    pub fn try_inline(slice: &[E]) -> Result<Self, Error> {
+        match slice.len() {
+            0..=256 => Ok(Self::from(slice)),
+            len     => Err(Error::Capacity { len, capacity: 256 }),
+        }
+    }
+}
+
+impl<E> Str<E> {
+    /// Checks that `len()` fits the variant's storage: the bucket size for inline variants and
+    /// the boxed slice's length for `Boxed`. Mutating methods check this in debug builds.
+    pub fn check_invariants(&self) -> Result<(), Error> {
+        let (len, capacity) = (self.len(), self.capacity());
+        if len > capacity {
+            return Err(Error::Capacity { len, capacity });
+        }
+
+        Ok(())
+    }
+
+    /// Returns how many elements fit without moving to a bigger bucket.
+    pub(crate) fn capacity(&self) -> usize {
+        match self {
+            Self::B8    { .. }      => 8,
+            Self::B16   { .. }      => 16,
+            Self::B32   { .. }      => 32,
+            Self::B64   { .. }      => 64,
+            Self::B128  { .. }      => 128,
+            Self::B256  { .. }      => 256,
+            Self::Boxed { buf, .. } => buf.len(),
+            Self::Empty             => 0
+        }
+    }
+
+    /// Returns the storage past `len()`, up to [`capacity`](Self::capacity).
+    pub(crate) fn spare_mut(&mut self) -> &mut [E] {
+        match self {
+            Self::B8    { buf, len } => &mut buf[*len as usize..],
+            Self::B16   { buf, len } => &mut buf[*len as usize..],
+            Self::B32   { buf, len } => &mut buf[*len as usize..],
+            Self::B64   { buf, len } => &mut buf[*len as usize..],
+            Self::B128  { buf, len } => &mut buf[*len as usize..],
+            Self::B256  { buf, len } => &mut buf[*len as usize..],
+            Self::Boxed { buf, len } => &mut buf[*len..],
+            Self::Empty => &mut []
+        }
+    }
+
+    /// Sets the length without touching the contents. For `UTF8`, callers must leave the first `new_len` bytes valid UTF-8.
+    pub(crate) fn set_len(&mut self, new_len: usize) {
+        assert!(new_len <= self.capacity(), "length {new_len} exceeds capacity {}", self.capacity());
+
+        match self {
+            Self::B8    { len, .. } |
+            Self::B16   { len, .. } |
+            Self::B32   { len, .. } |
+            Self::B64   { len, .. } |
+            Self::B128  { len, .. } => *len = new_len as _,
+            Self::B256  { len, .. } => *len = new_len as _,
+            Self::Boxed { len, .. } => *len = new_len,
+            Self::Empty => debug_assert_eq!(new_len, 0)
+        }
+    }
+}
+
+/// Compares the stored elements, whatever bucket holds them, so a clone equals its original.
+impl<E: PartialEq> PartialEq for Str<E> {
     fn eq(&self, other: &Self) -> bool {
-        self.as_ptr() == other.as_ptr() && self.len() == other.len()
+        self.len() == other.len() && self.elements() == other.elements()
     }
 }
-impl<E> Eq for Str<E> { }
+impl<E: Eq> Eq for Str<E> { }
 
-impl<E> PartialOrd for Str<E> {
+impl<E: Ord> PartialOrd for Str<E> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.len().partial_cmp(&other.len())
+        Some(self.cmp(other))
     }
 }
 
-impl<E> Ord for Str<E> {
+/// Orders by length first, then by the stored elements, which keeps ordering consistent with equality.
+impl<E: Ord> Ord for Str<E> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.len().cmp(&other.len())
+        self.len().cmp(&other.len()).then_with(|| self.elements().cmp(other.elements()))
     }
 }
 
+/// Hashes the stored elements followed by a `0xff` terminator, independent of the bucket.
+///
+/// For `UTF8` this is exactly what `str` feeds the hasher, so a `UTF8` and a `str` holding the same text
+/// hash the same. The `equivalent` feature relies on this to look up `UTF8` keys by `&str`.
 impl<E: Hash> Hash for Str<E> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state);
-        
-        match self {
-            Str::Empty => { }
-            Str::B8    { buf, len } => (&buf[..*len as usize]).hash(state),
-            Str::B16   { buf, len } => (&buf[..*len as usize]).hash(state),
-            Str::B32   { buf, len } => (&buf[..*len as usize]).hash(state),
-            Str::B64   { buf, len } => (&buf[..*len as usize]).hash(state),
-            Str::B128  { buf, len } => (&buf[..*len as usize]).hash(state),
-            Str::B256  { buf, len } => (&buf[..*len as usize]).hash(state),
-            Str::Boxed { buf, len } => (&buf[..*len]).hash(state),
-        }
+        E::hash_slice(self.elements(), state);
+        state.write_u8(0xff);
+    }
+}
+
+impl std::fmt::Debug for UTF8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_ref() as &str, f)
+    }
+}
+
+impl std::fmt::Debug for Blob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Blob").field(&self.as_bytes()).finish()
+    }
+}
+
+impl Blob {
+    /// Returns the stored bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.elements()
+    }
+
+    /// Appends a byte, promoting to a bigger bucket or to the heap as needed.
+    pub fn push(&mut self, byte: u8) {
+        self.0.extend_from_slice(&[byte]);
+    }
+
+    /// Appends `bytes`, promoting to a bigger bucket or to the heap as needed.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    /// Removes and returns the last byte, keeping the bucket.
+    pub fn pop(&mut self) -> Option<u8> {
+        let byte = *self.as_bytes().last()?;
+        self.0.set_len(self.0.len() - 1);
+        Some(byte)
+    }
+
+    /// Removes every byte, keeping the bucket or heap buffer for reuse.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Compares the stored bytes, so validated text and raw byte fields can be checked against each other directly.
+impl PartialEq<Blob> for UTF8 {
+    fn eq(&self, other: &Blob) -> bool {
+        self.elements() == other.as_bytes()
+    }
+}
+
+impl PartialEq<UTF8> for Blob {
+    fn eq(&self, other: &UTF8) -> bool {
+        self.as_bytes() == other.elements()
+    }
+}
+
+/// Orders like `Ord for Str`, by length and then by the stored bytes, so mixing the two types never
+/// disagrees with comparing two `UTF8`s.
+impl PartialOrd<Blob> for UTF8 {
+    fn partial_cmp(&self, other: &Blob) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(&other.0))
+    }
+}
+
+impl PartialOrd<UTF8> for Blob {
+    fn partial_cmp(&self, other: &UTF8) -> Option<std::cmp::Ordering> {
+        Some(self.0.cmp(other))
+    }
+}
+
+impl Default for Blob {
+    fn default() -> Self {
+        Self(Str::Empty)
+    }
+}
+
+impl From<&[u8]> for Blob {
+    fn from(bytes: &[u8]) -> Self {
+        Self(Str::from(bytes))
     }
 }
 
 impl AsRef<str> for UTF8 {
     fn as_ref(&self) -> &str {
-        unsafe { std::mem::transmute(self.as_slice()) }
+        self.text()
     }
 }
 
 impl std::ops::Deref for UTF8 {
     type Target = str;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self.as_slice()) }
+        self.text()
+    }
+}
+
+impl UTF8 {
+    #[cfg(not(feature = "safe"))]
+    fn text(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(self.as_slice()) }
+    }
+
+    /// Revalidates on every access, since nothing but `UTF8`'s constructors vouches for the bytes.
+    #[cfg(feature = "safe")]
+    fn text(&self) -> &str {
+        std::str::from_utf8(self.elements()).expect("UTF8 holds invalid UTF-8")
     }
 }
 
@@ -162,7 +522,7 @@ impl From<&str> for UTF8 {
             129..=256 => {
                 let mut buf = [0u8; 256];
                 buf[..len].copy_from_slice(bytes);
-                Self::B256 { buf, len: len as u8 }
+                Self::B256 { buf, len: len as u16 }
             }
 
             _ => Self::Boxed { buf: Box::from(value.as_bytes()), len }
@@ -170,40 +530,40 @@ impl From<&str> for UTF8 {
     }
 }
 
-impl From<&[u8]> for UTF8 {
-    fn from(slice: &[u8]) -> Self {
+impl<E: Copy + Default> From<&[E]> for Str<E> {
+    fn from(slice: &[E]) -> Self {
         let len = slice.len();
         match len {
             0 => Self::Empty,
 
             1..=8 => {
-                let mut buf = [0u8; 8];
+                let mut buf = [E::default(); 8];
                 buf[..len].copy_from_slice(slice);
                 Self::B8 { buf, len: len as u8 }
             }
 
             9..=16 => {
-                let mut buf = [0u8; 16];
+                let mut buf = [E::default(); 16];
                 buf[..len].copy_from_slice(slice);
                 Self::B16 { buf, len: len as u8 }
             }
 
             17..=64 => {
-                let mut buf = [0u8; 64];
+                let mut buf = [E::default(); 64];
                 buf[..len].copy_from_slice(slice);
                 Self::B64 { buf, len: len as u8 }
             }
 
             65..=128 => {
-                let mut buf = [0u8; 128];
+                let mut buf = [E::default(); 128];
                 buf[..len].copy_from_slice(slice);
                 Self::B128 { buf, len: len as u8 }
             }
 
             129..=256 => {
-                let mut buf = [0u8; 256];
+                let mut buf = [E::default(); 256];
                 buf[..len].copy_from_slice(slice);
-                Self::B256 { buf, len: len as u8 }
+                Self::B256 { buf, len: len as u16 }
             }
 
             _ => Self::Boxed { buf: Box::from(slice), len }
@@ -226,54 +586,776 @@ impl From<UTF8> for String {
     fn from(value: UTF8) -> Self {
         match value {
             UTF8::Empty => String::new(),
-            UTF8::Boxed { buf, len } => unsafe { String::from_raw_parts(Box::into_raw(buf) as *mut u8, len as usize, len)}
+            #[cfg(not(feature = "safe"))]
+            UTF8::Boxed { buf, len } => {
+                let cap = buf.len();
+                unsafe { String::from_raw_parts(Box::into_raw(buf) as *mut u8, len, cap) }
+            }
+
+            #[cfg(feature = "safe")]
+            UTF8::Boxed { buf, len } => {
+                let mut vec = Vec::from(buf);
+                vec.truncate(len);
+                String::from_utf8(vec).expect("UTF8 holds invalid UTF-8")
+            }
             _ => value.as_ref().to_string()
         }
     }
 }
 
-#[cfg(feature = "napi")]
-mod napi_impl {
-    use napi::{bindgen_prelude::FromNapiValue, Status, sys::*, *};
-    use std::os::raw::c_char;
-    use crate::UTF8;
-    
-    impl FromNapiValue for UTF8 {
-        unsafe fn from_napi_value(env: napi_env, value: napi_value) -> Result<Self> {
-            let mut needed = 0;
-            let status = napi_get_value_string_utf8(env, value, std::ptr::null_mut(), 0, &mut needed);
-
-            if status != 0 /* napi_ok */ {
-                return Err(Error::new(Status::from(status), "Failed to get string size"));
-            }
+impl UTF8 {
+    /// Like [`check_invariants`](Str::check_invariants), and also checks that the stored bytes are valid UTF-8.
+    /// Methods that append text check this in debug builds.
+    pub fn check_utf8(&self) -> Result<(), Error> {
+        self.check_invariants()?;
+        std::str::from_utf8(self.elements())?;
+        Ok(())
+    }
 
-            if needed <= 256 {
-                let mut written = 0;
-                let mut buf     = [0u8; 257];
-                let status = napi_get_value_string_utf8(env, value, buf.as_mut_ptr() as *mut c_char, needed + 1, &mut written);
+    /// Copies `bytes` into a `UTF8` after checking that they are valid UTF-8.
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from(std::str::from_utf8(bytes)?))
+    }
 
-                if status != 0 /* napi_ok */ {
-                    return Err(Error::new(Status::from(status), "Failed stack read"));
-                }
+    /// Checks that the bytes in `bytes` are valid UTF-8 and takes over their storage, whatever the bucket.
+    /// On failure the buffer is handed back with the error.
+    // The buffer is returned by value so a failed check never costs an allocation.
+    #[allow(clippy::result_large_err)]
+    pub fn from_bytes(bytes: Blob) -> Result<Self, (std::str::Utf8Error, Blob)> {
+        match std::str::from_utf8(bytes.as_bytes()) {
+            Ok(_)  => Ok(bytes.0),
+            Err(e) => Err((e, bytes)),
+        }
+    }
+
+    /// Takes over the storage of `bytes` without checking it.
+    ///
+    /// # Safety
+    ///
+    /// The bytes in `bytes` must be valid UTF-8.
+    #[cfg_attr(feature = "safe", allow(unsafe_code))]
+    pub unsafe fn from_bytes_unchecked(bytes: Blob) -> Self {
+        debug_assert_eq!(bytes.0.check_utf8(), Ok(()));
+        bytes.0
+    }
+
+    /// Hands the storage over as raw bytes, without copying.
+    pub fn into_byte_str(self) -> Blob {
+        Blob(self)
+    }
+
+    /// Decodes UTF-16 code units into a `UTF8`, staying inline when the encoded result fits in 256 bytes.
+    pub fn from_utf16(units: &[u16]) -> Result<Self, std::char::DecodeUtf16Error> {
+        Self::try_from_chars(std::char::decode_utf16(units.iter().copied()))
+    }
+
+    /// Like [`from_utf16`](Self::from_utf16), replacing unpaired surrogates with `U+FFFD`.
+    pub fn from_utf16_lossy(units: &[u16]) -> Self {
+        let chars = std::char::decode_utf16(units.iter().copied())
+            .map(|c| Ok::<_, std::convert::Infallible>(c.unwrap_or(char::REPLACEMENT_CHARACTER)));
+
+        match Self::try_from_chars(chars) { Ok(value) => value }
+    }
+
+    /// Transcodes Latin-1 bytes into a `UTF8`; pure ASCII input is copied as-is.
+    pub fn from_latin1(bytes: &[u8]) -> Self {
+        if bytes.is_ascii() {
+            return Self::from(bytes);
+        }
+
+        let chars = bytes.iter().map(|&b| Ok::<_, std::convert::Infallible>(char::from(b)));
+        match Self::try_from_chars(chars) { Ok(value) => value }
+    }
+
+    /// Decodes the body of a JSON string literal, without its quotes, staying inline when the result fits in
+    /// 256 bytes. Handles the escapes `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX`, including
+    /// surrogate pairs. Input without a backslash is copied as-is.
+    ///
+    /// Fails with [`Error::Conversion`] on an unknown or truncated escape, an unpaired surrogate, or an
+    /// unescaped control character.
+    pub fn from_json_escaped(text: &str) -> Result<Self, Error> {
+        if !text.bytes().any(|b| b == b'\\' || b < 0x20) {
+            return Ok(Self::from(text));
+        }
+
+        let mut chars = text.chars();
+        let decoded = std::iter::from_fn(|| {
+            let c = chars.next()?;
+            Some(match c {
+                '\\' => json_escape(&mut chars),
+                '\0'..='\x1f' => Err(Error::Conversion("unescaped control character in JSON string")),
+                c => Ok(c),
+            })
+        });
+
+        Self::try_from_chars(decoded)
+    }
+
+    /// Reads an environment variable. Short values are moved into an inline bucket, so the `String`
+    /// that `std::env::var` returns does not outlive the call.
+    pub fn from_env<K: AsRef<std::ffi::OsStr>>(key: K) -> Result<Self, std::env::VarError> {
+        std::env::var(key).map(Self::from)
+    }
+
+    /// Like [`from_env`](Self::from_env), replacing invalid UTF-8 with `U+FFFD`. Returns `None` if the variable is unset.
+    pub fn from_env_lossy<K: AsRef<std::ffi::OsStr>>(key: K) -> Option<Self> {
+        std::env::var_os(key).map(|value| match value.into_string() {
+            Ok(value) => Self::from(value),
+            Err(value) => Self::from(value.to_string_lossy().as_ref()),
+        })
+    }
+
+    /// Renders `args` into a stack buffer, moving to a `String` only once 256 bytes are exceeded.
+    /// This is what [`format_utf8!`] expands to.
+    pub fn from_fmt(args: std::fmt::Arguments<'_>) -> Self {
+        Self::try_from_fmt(args).expect("a formatting trait implementation returned an error")
+    }
+
+    /// Like [`from_fmt`](Self::from_fmt), but passes on a formatting error instead of panicking.
+    pub(crate) fn try_from_fmt(args: std::fmt::Arguments<'_>) -> Result<Self, std::fmt::Error> {
+        if let Some(s) = args.as_str() {
+            return Ok(Self::from(s));
+        }
+
+        let mut out = FmtBuffer { buf: [0; 256], len: 0, spill: None };
+        std::fmt::write(&mut out, args)?;
+
+        Ok(match out.spill {
+            Some(s) => Self::from(s),
+            None => Self::from(&out.buf[..out.len]),
+        })
+    }
+
+    /// Counts the characters. Pure ASCII text, found with one word-at-a-time scan, is answered by its length.
+    pub fn char_count(&self) -> usize {
+        let bytes = self.elements();
+        if bytes.is_ascii() { bytes.len() } else { self.text().chars().count() }
+    }
+
+    /// Copies the characters at indices `chars` into a new `UTF8`, inline when it fits. See
+    /// [`char_slice_ref`](Self::char_slice_ref).
+    pub fn char_range(&self, chars: std::ops::Range<usize>) -> Self {
+        Self::from(self.char_slice_ref(chars))
+    }
+
+    /// Borrows the characters at indices `chars`, translating them to byte offsets. Pure ASCII text is sliced
+    /// directly.
+    ///
+    /// Panics if the range is decreasing or ends past [`char_count`](Self::char_count).
+    pub fn char_slice_ref(&self, chars: std::ops::Range<usize>) -> &str {
+        let text = self.text();
+        assert!(chars.start <= chars.end, "char range starts at {} but ends at {}", chars.start, chars.end);
+
+        if text.is_ascii() {
+            assert!(chars.end <= text.len(), "char index {} out of range for {} characters", chars.end, text.len());
+            return &text[chars];
+        }
+
+        let mut offsets = text.char_indices().map(|(i, _)| i).chain(std::iter::once(text.len()));
+        let start = offsets.nth(chars.start);
+        let end   = if chars.start == chars.end { start } else { offsets.nth(chars.end - chars.start - 1) };
+
+        match (start, end) {
+            (Some(start), Some(end)) => &text[start..end],
+            _ => panic!("char index {} out of range for {} characters", chars.end, self.char_count()),
+        }
+    }
+
+    /// Lowercases into a new `UTF8`. ASCII text is copied once and lowered in place, staying inline when it fits;
+    /// anything else goes through `str::to_lowercase`.
+    pub fn to_lowercase_utf8(&self) -> Self {
+        if self.elements().is_ascii() {
+            return self.map_ascii(<[u8]>::make_ascii_lowercase);
+        }
+
+        Self::from(self.text().to_lowercase())
+    }
+
+    /// Uppercases into a new `UTF8`, with the same ASCII fast path as [`to_lowercase_utf8`](Self::to_lowercase_utf8).
+    pub fn to_uppercase_utf8(&self) -> Self {
+        if self.elements().is_ascii() {
+            return self.map_ascii(<[u8]>::make_ascii_uppercase);
+        }
+
+        Self::from(self.text().to_uppercase())
+    }
+
+    /// Compares with `other` ignoring case. When both sides are ASCII this is a byte-wise `eq_ignore_ascii_case`;
+    /// otherwise both are compared by their full Unicode lowercase mappings.
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        let (left, right) = (self.elements(), other.as_bytes());
+        if left.is_ascii() && right.is_ascii() {
+            return left.eq_ignore_ascii_case(right);
+        }
+
+        self.text().chars().flat_map(char::to_lowercase).eq(other.chars().flat_map(char::to_lowercase))
+    }
+
+    /// Copies the bytes and applies `map` to the copy, staying inline when it fits. Only for ASCII-preserving maps.
+    pub(crate) fn map_ascii(&self, map: impl FnOnce(&mut [u8])) -> Self {
+        let bytes = self.elements();
+
+        if bytes.len() <= 256 {
+            let mut buf = [0u8; 256];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            map(&mut buf[..bytes.len()]);
+            return Self::from(&buf[..bytes.len()]);
+        }
 
-                return std::str::from_utf8(&buf[..written])
-                    .map_err(|error| Error::from_reason(error.to_string()))
-                    .map(UTF8::from)
+        let mut vec = bytes.to_vec();
+        map(&mut vec);
+        Self::Boxed { len: vec.len(), buf: vec.into_boxed_slice() }
+    }
+
+    /// Encodes `chars` into a stack buffer, moving to a `String` only once 256 bytes are exceeded.
+    pub(crate) fn try_from_chars<E>(mut chars: impl Iterator<Item = Result<char, E>>) -> Result<Self, E> {
+        let mut buf = [0u8; 256];
+        let mut len = 0;
+
+        for c in chars.by_ref() {
+            let c = c?;
+            if len + c.len_utf8() > buf.len() {
+                let mut s = String::from(std::str::from_utf8(&buf[..len]).expect("encoded by char::encode_utf8"));
+                s.push(c);
+                for c in chars { s.push(c?) }
+                return Ok(Self::from(s));
             }
 
-            let mut written = 0;
-            let mut vec     = Vec::with_capacity(needed + 1);
+            len += c.encode_utf8(&mut buf[len..]).len();
+        }
+
+        Ok(Self::from(&buf[..len]))
+    }
+}
+
+impl UTF8 {
+    /// Appends a character, promoting to a bigger bucket or to the heap as needed.
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
 
-            let status = napi_get_value_string_utf8(env, value, vec.as_mut_ptr() as *mut c_char, needed + 1, &mut written);
+    /// Appends `text`, promoting to a bigger bucket or to the heap as needed.
+    pub fn push_str(&mut self, text: &str) {
+        self.extend_from_slice(text.as_bytes());
+        debug_assert_eq!(self.check_utf8(), Ok(()));
+    }
 
-            if status != 0 /* napi_ok */ {
-                return Err(Error::new(Status::from(status), "Failed heap read"));
+    /// Removes and returns the last character, keeping the bucket.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.text().chars().next_back()?;
+        self.set_len(self.len() - c.len_utf8());
+        Some(c)
+    }
+}
+
+impl Extend<char> for UTF8 {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|c| self.push(c));
+    }
+}
+
+impl<'a> Extend<&'a str> for UTF8 {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|text| self.push_str(text));
+    }
+}
+
+/// Appends formatted text, so `write!` works on a `UTF8` directly.
+impl std::fmt::Write for UTF8 {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+/// Stack-first sink behind [`UTF8::from_fmt`].
+struct FmtBuffer {
+    buf:   [u8; 256],
+    len:   usize,
+    spill: Option<String>,
+}
+
+impl std::fmt::Write for FmtBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if let Some(spill) = &mut self.spill {
+            spill.push_str(s);
+        } else if self.len + s.len() <= self.buf.len() {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+        } else {
+            let mut spill = String::with_capacity((self.len + s.len()) * 2);
+            spill.push_str(std::str::from_utf8(&self.buf[..self.len]).expect("filled from &str"));
+            spill.push_str(s);
+            self.spill = Some(spill);
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes one JSON escape for [`UTF8::from_json_escaped`], with the backslash already consumed.
+fn json_escape(chars: &mut std::str::Chars<'_>) -> Result<char, Error> {
+    let unit = match chars.next() {
+        Some('"')  => return Ok('"'),
+        Some('\\') => return Ok('\\'),
+        Some('/')  => return Ok('/'),
+        Some('b')  => return Ok('\u{8}'),
+        Some('f')  => return Ok('\u{c}'),
+        Some('n')  => return Ok('\n'),
+        Some('r')  => return Ok('\r'),
+        Some('t')  => return Ok('\t'),
+        Some('u')  => json_hex4(chars)?,
+        Some(_)    => return Err(Error::Conversion("unknown escape in JSON string")),
+        None       => return Err(Error::Conversion("truncated escape in JSON string")),
+    };
+
+    let unpaired = Error::Conversion("unpaired surrogate in JSON string");
+    match unit {
+        0xd800..=0xdbff => {
+            if chars.next() != Some('\\') || chars.next() != Some('u') {
+                return Err(unpaired);
             }
 
-            vec.set_len(written);
-            String::from_utf8(vec)
-                .map_err(|error| Error::from_reason(error.to_string()))
-                .map(UTF8::from)
+            let low = json_hex4(chars)?;
+            if !(0xdc00..=0xdfff).contains(&low) {
+                return Err(unpaired);
+            }
+
+            let c = 0x10000 + ((unit as u32 - 0xd800) << 10) + (low as u32 - 0xdc00);
+            Ok(char::from_u32(c).expect("a surrogate pair is always a valid scalar"))
+        }
+        0xdc00..=0xdfff => Err(unpaired),
+        unit => Ok(char::from_u32(unit as u32).expect("a non-surrogate BMP unit is a valid scalar")),
+    }
+}
+
+/// Reads the four hex digits of a `\u` escape.
+fn json_hex4(chars: &mut std::str::Chars<'_>) -> Result<u16, Error> {
+    let mut unit = 0;
+    for _ in 0..4 {
+        let digit = chars.next().and_then(|c| c.to_digit(16));
+        unit = unit << 4 | digit.ok_or(Error::Conversion("invalid \\u escape in JSON string"))? as u16;
+    }
+
+    Ok(unit)
+}
+
+/// Like `format!`, but produces a [`UTF8`]. Results up to 256 bytes never allocate.
+#[macro_export]
+macro_rules! format_utf8 {
+    ($($arg:tt)*) => {
+        $crate::UTF8::from_fmt(::std::format_args!($($arg)*))
+    };
+}
+
+impl Str<u16> {
+    /// Transcodes the stored UTF-16 code units into a `UTF8`.
+    pub fn to_utf8(&self) -> Result<UTF8, std::char::DecodeUtf16Error> {
+        UTF8::from_utf16(self.elements())
+    }
+}
+
+pub mod io;
+
+pub mod table;
+
+pub mod lex;
+
+mod error;
+
+mod hashed;
+
+mod ascii;
+
+mod chain;
+
+mod atom;
+
+mod pool;
+
+mod prefixed;
+
+mod collation;
+
+mod net;
+
+mod hexdump;
+
+mod line_index;
+
+#[cfg(feature = "ffi")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+pub mod ffi;
+
+#[cfg(feature = "uniffi")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod uniffi_impl;
+
+#[cfg(feature = "cxx")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod cxx_impl;
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+
+#[cfg(feature = "postgres")]
+mod postgres_impl;
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod diesel_impl;
+
+#[cfg(feature = "redis")]
+mod redis_impl;
+
+#[cfg(feature = "http")]
+mod http_impl;
+
+#[cfg(feature = "axum")]
+mod axum_impl;
+
+#[cfg(feature = "actix")]
+mod actix_impl;
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql_impl;
+
+#[cfg(feature = "log")]
+mod log_impl;
+
+#[cfg(feature = "valuable")]
+mod valuable_impl;
+
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+
+#[cfg(feature = "ufmt")]
+mod ufmt_impl;
+
+#[cfg(feature = "itoa")]
+mod itoa_impl;
+
+#[cfg(feature = "ryu")]
+mod ryu_impl;
+
+#[cfg(feature = "uuid")]
+mod uuid_impl;
+
+#[cfg(feature = "time")]
+mod time_impl;
+
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+
+#[cfg(feature = "rand")]
+mod rand_impl;
+
+#[cfg(feature = "compact_str")]
+mod compact_str_impl;
+
+#[cfg(feature = "smol_str")]
+mod smol_str_impl;
+
+#[cfg(feature = "arcstr")]
+mod arcstr_impl;
+
+#[cfg(feature = "smartstring")]
+mod smartstring_impl;
+
+#[cfg(feature = "regex")]
+mod regex_impl;
+
+#[cfg(feature = "bstr")]
+mod bstr_impl;
+
+#[cfg(feature = "equivalent")]
+mod equivalent_impl;
+
+#[cfg(feature = "unicode-segmentation")]
+mod editor;
+
+// Exempt from `safe`: the freelist rebuilds boxes from raw pointers. See the module docs.
+#[cfg(feature = "freelist")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+pub mod freelist;
+
+// Without the feature, spills always allocate.
+#[cfg(not(feature = "freelist"))]
+mod freelist {
+    pub(crate) fn take<E>(_: usize) -> Option<Box<[E]>> { None }
+
+    #[allow(clippy::extra_unused_type_parameters)]
+    pub(crate) fn size_class<E>(_: usize) -> Option<usize> { None }
+}
+
+#[cfg(feature = "icu_collator")]
+mod icu_collator_impl;
+
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+
+#[cfg(feature = "fuzzy")]
+mod fuzzy;
+
+#[cfg(feature = "camino")]
+mod camino_impl;
+
+#[cfg(feature = "tokio")]
+mod tokio_impl;
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod bytes_impl;
+
+#[cfg(feature = "tokio-util")]
+mod tokio_util_impl;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+
+#[cfg(feature = "utoipa")]
+mod utoipa_impl;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "serde")]
+pub mod serde_seq;
+
+#[cfg(feature = "serde_json")]
+pub mod json;
+
+#[cfg(feature = "bson")]
+mod bson_impl;
+
+#[cfg(feature = "neon")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod neon_impl;
+
+#[cfg(feature = "v8")]
+mod v8_impl;
+
+#[cfg(feature = "pyo3")]
+mod pyo3_impl;
+
+#[cfg(feature = "jni")]
+mod jni_impl;
+
+#[cfg(all(feature = "objc", target_vendor = "apple"))]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod objc_impl;
+
+#[cfg(feature = "napi")]
+#[cfg_attr(feature = "safe", allow(unsafe_code))]
+mod napi_impl;
+
+pub use error::{Error, ErrorMessage};
+
+pub use hashed::HashedUTF8;
+
+pub use ascii::AsciiUTF8;
+
+pub use chain::Utf8Chain;
+
+pub use atom::Atom;
+
+pub use pool::{Pool, PoolStr};
+
+pub use prefixed::PrefixedUTF8;
+
+pub use collation::Collation;
+
+pub use hexdump::HexDump;
+
+pub use line_index::{ColumnMode, LineCol, LineIndex};
+
+#[cfg(feature = "napi")]
+pub use napi_impl::{JsObjectExt, Utf8Array, get_named_property_utf8};
+
+#[cfg(feature = "rand")]
+pub use rand_impl::AlphanumericUtf8;
+
+#[cfg(feature = "cxx")]
+pub use cxx_impl::CXX_INCLUDE_DIRS;
+
+#[cfg(feature = "derive")]
+pub use kaff_sso_derive::AsUtf8;
+
+#[cfg(feature = "unicode-segmentation")]
+pub use editor::Utf8Editor;
+
+#[cfg(feature = "actix")]
+pub use actix_impl::Utf8ExtractFut;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A value at the full capacity of every variant, from `Empty` to a `Boxed` buffer with spare room.
+    fn full_of_each_variant() -> Vec<UTF8> {
+        vec![
+            Str::Empty,
+            Str::B8    { buf: [b'a';   8], len:   8 },
+            Str::B16   { buf: [b'a';  16], len:  16 },
+            Str::B32   { buf: [b'a';  32], len:  32 },
+            Str::B64   { buf: [b'a';  64], len:  64 },
+            Str::B128  { buf: [b'a'; 128], len: 128 },
+            Str::B256  { buf: [b'a'; 256], len: 256 },
+            Str::Boxed { buf: vec![b'a'; 512].into_boxed_slice(), len: 300 },
+        ]
+    }
+
+    #[test]
+    fn valid_values_pass_both_checks() {
+        for value in full_of_each_variant() {
+            assert_eq!(value.check_invariants(), Ok(()));
+            assert_eq!(value.check_utf8(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn length_past_inline_storage_is_rejected() {
+        let cases = [
+            (Str::B8    { buf: [0;   8], len:   9 },   9,   8),
+            (Str::B16   { buf: [0;  16], len:  17 },  17,  16),
+            (Str::B32   { buf: [0;  32], len:  33 },  33,  32),
+            (Str::B64   { buf: [0;  64], len:  65 },  65,  64),
+            (Str::B128  { buf: [0; 128], len: 129 }, 129, 128),
+            (Str::B256  { buf: [0; 256], len: 257 }, 257, 256),
+        ];
+
+        for (value, len, capacity) in cases {
+            assert_eq!(value.check_invariants(), Err(Error::Capacity { len, capacity }));
+            assert_eq!(value.check_utf8(), Err(Error::Capacity { len, capacity }));
+        }
+    }
+
+    #[test]
+    fn boxed_length_past_its_buffer_is_rejected() {
+        let value: UTF8 = Str::Boxed { buf: vec![b'a'; 4].into_boxed_slice(), len: 10 };
+        assert_eq!(value.check_invariants(), Err(Error::Capacity { len: 10, capacity: 4 }));
+        assert_eq!(value.check_utf8(), Err(Error::Capacity { len: 10, capacity: 4 }));
+    }
+
+    #[test]
+    fn invalid_utf8_is_rejected_by_check_utf8_only() {
+        let value = Blob::from(&[b'a', 0xff, b'b'][..]).0;
+        assert_eq!(value.check_invariants(), Ok(()));
+        assert!(matches!(value.check_utf8(), Err(Error::Utf8(error)) if error.valid_up_to() == 1));
+    }
+
+    #[test]
+    fn mutations_keep_values_valid() {
+        let mut value = UTF8::Empty;
+        for i in 0..400 {
+            value.push(if i % 3 == 0 { 'é' } else { 'a' });
+            assert_eq!(value.check_utf8(), Ok(()));
+        }
+
+        while value.pop().is_some() {
+            assert_eq!(value.check_utf8(), Ok(()));
+        }
+
+        value.push_str("text");
+        value.shrink_to_fit();
+        assert_eq!(value.check_utf8(), Ok(()));
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn derived_names_match_from_str() {
+        #[derive(AsUtf8)]
+        #[kaff_sso(crate = crate)]
+        #[allow(non_camel_case_types)]
+        enum Name {
+            r#type,
+            SixteenBytesLong,
+            SeventeenBytesLong,
+            AVariantNameThatIsLongerThanSixtyFourBytesSoItLandsInTheB128Bucket,
         }
+
+        for (name, text) in [
+            (Name::r#type, "type"),
+            (Name::SixteenBytesLong, "SixteenBytesLong"),
+            (Name::SeventeenBytesLong, "SeventeenBytesLong"),
+            (
+                Name::AVariantNameThatIsLongerThanSixtyFourBytesSoItLandsInTheB128Bucket,
+                "AVariantNameThatIsLongerThanSixtyFourBytesSoItLandsInTheB128Bucket",
+            ),
+        ] {
+            let value = name.as_utf8();
+            assert_eq!(value, UTF8::from(text));
+            assert_eq!(std::mem::discriminant(&value), std::mem::discriminant(&UTF8::from(text)));
+        }
+    }
+
+    #[test]
+    fn json_surrogate_pairs_decode_to_one_char() {
+        assert_eq!(UTF8::from_json_escaped(r"a\ud83d\ude00b").unwrap(), UTF8::from("a\u{1f600}b"));
+        assert_eq!(UTF8::from_json_escaped(r"\uD834\uDD1E").unwrap(), UTF8::from("\u{1d11e}"));
+    }
+
+    #[test]
+    fn json_lone_surrogates_are_rejected() {
+        for text in [r"\ud83d", r"\ud83dx", r"\ud83dA", r"\ud83d\u0041", r"\ud83d\n", r"\ude00", r"\ude00\ud83d"] {
+            assert!(matches!(UTF8::from_json_escaped(text), Err(Error::Conversion(_))), "{text}");
+        }
+    }
+
+    #[test]
+    fn json_nul_escape_decodes_to_nul() {
+        assert_eq!(UTF8::from_json_escaped(r"a\u0000b").unwrap(), UTF8::from("a\0b"));
+        assert!(UTF8::from_json_escaped("a\0b").is_err());
+    }
+
+    #[test]
+    fn json_results_stay_inline_up_to_256_bytes() {
+        let full = UTF8::from_json_escaped(&r"\n".repeat(256)).unwrap();
+        assert!(matches!(full, Str::B256 { len: 256, .. }));
+        assert_eq!(full, UTF8::from("\n".repeat(256)));
+
+        let small = UTF8::from_json_escaped(r"\u00e9t\u00e9").unwrap();
+        assert!(matches!(small, Str::B8 { len: 5, .. }));
+
+        let over = UTF8::from_json_escaped(&r"\t".repeat(257)).unwrap();
+        assert!(matches!(over, Str::Boxed { len: 257, .. }));
+    }
+
+    #[test]
+    fn a_full_b256_keeps_its_length() {
+        let text  = "a".repeat(256);
+        let value = UTF8::from(text.as_str());
+
+        assert!(matches!(value, Str::B256 { len: 256, .. }));
+        assert_eq!(String::from(value), text);
+    }
+
+    #[test]
+    fn blob_order_agrees_with_utf8_order() {
+        let values = ["", "b", "ab", "ba", "abc", "\u{e9}", "zz"];
+
+        for a in values {
+            for b in values {
+                let (a, b) = (UTF8::from(a), UTF8::from(b));
+                assert_eq!(a.partial_cmp(&Blob(b.clone())), Some(a.cmp(&b)));
+                assert_eq!(Blob(a.clone()).partial_cmp(&b), Some(a.cmp(&b)));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "left == right")]
+    fn appending_to_invalid_text_trips_the_debug_check() {
+        let mut value: UTF8 = Str::B8 { buf: [0xff; 8], len: 1 };
+        value.push_str("a");
     }
 }
@@ -0,0 +1,205 @@
+use crate::UTF8;
+
+/// How columns are counted by [`LineIndex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnMode {
+    /// Bytes from the start of the line.
+    Utf8,
+    /// UTF-16 code units from the start of the line, as used by the Language Server Protocol by default.
+    Utf16,
+}
+
+/// A zero-based line and column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line:   usize,
+    pub column: usize,
+}
+
+/// Source text with its line starts precomputed, for turning byte offsets into line/column positions
+/// and back.
+///
+/// Lines end after each `\n`, so a `\r` before it counts as the last character of its line. Lookups are
+/// a binary search over the line starts; UTF-16 columns then scan the one line, and pure ASCII lines are
+/// answered by their byte offset.
+#[derive(Clone, Debug)]
+pub struct LineIndex {
+    text:        UTF8,
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Indexes `text`.
+    pub fn new(text: UTF8) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.elements().iter().enumerate().filter(|&(_, &b)| b == b'\n').map(|(i, _)| i + 1))
+            .collect();
+
+        Self { text, line_starts }
+    }
+
+    /// Returns the indexed text.
+    pub fn text(&self) -> &str {
+        self.text.text()
+    }
+
+    /// Hands the indexed text back.
+    pub fn into_inner(self) -> UTF8 {
+        self.text
+    }
+
+    /// Returns the number of lines. Empty text and text ending in `\n` both have a final, empty line.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the byte offset at which `line` starts.
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line).copied()
+    }
+
+    /// Returns the text of `line`, including its line break.
+    pub fn line(&self, line: usize) -> Option<&str> {
+        let start = self.line_start(line)?;
+        Some(&self.text()[start..self.line_end(line)])
+    }
+
+    /// Maps a byte offset to its line and column. `None` if `offset` is past the end or not on a character
+    /// boundary; the end of the text itself is a valid position.
+    pub fn line_col(&self, offset: usize, mode: ColumnMode) -> Option<LineCol> {
+        let text = self.text();
+        if !text.is_char_boundary(offset) {
+            return None;
+        }
+
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        Some(LineCol { line, column: column(&text[self.line_starts[line]..offset], mode) })
+    }
+
+    /// Maps a line and column back to a byte offset. `None` if the line does not exist, the column is past
+    /// the end of the line, or it falls inside a character (or, in UTF-16 mode, between the two halves of a
+    /// surrogate pair).
+    pub fn offset(&self, position: LineCol, mode: ColumnMode) -> Option<usize> {
+        let start = self.line_start(position.line)?;
+        let line  = &self.text()[start..self.line_end(position.line)];
+
+        match mode {
+            ColumnMode::Utf8 => (position.column <= line.len() && line.is_char_boundary(position.column))
+                .then_some(start + position.column),
+            ColumnMode::Utf16 if line.is_ascii() => (position.column <= line.len()).then_some(start + position.column),
+            ColumnMode::Utf16 => {
+                let mut units = 0;
+                for (i, c) in line.char_indices() {
+                    if units == position.column {
+                        return Some(start + i);
+                    }
+                    if units > position.column {
+                        return None;
+                    }
+                    units += c.len_utf16();
+                }
+
+                (units == position.column).then_some(start + line.len())
+            }
+        }
+    }
+
+    fn line_end(&self, line: usize) -> usize {
+        self.line_starts.get(line + 1).copied().unwrap_or(self.text().len())
+    }
+}
+
+/// Measures `before`, the text between a line start and a position, as a column.
+pub(crate) fn column(before: &str, mode: ColumnMode) -> usize {
+    match mode {
+        ColumnMode::Utf8 => before.len(),
+        ColumnMode::Utf16 if before.is_ascii() => before.len(),
+        ColumnMode::Utf16 => before.encode_utf16().count(),
+    }
+}
+
+impl From<UTF8> for LineIndex {
+    fn from(text: UTF8) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for LineIndex {
+    fn from(text: &str) -> Self {
+        Self::new(UTF8::from(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: usize, column: usize) -> LineCol {
+        LineCol { line, column }
+    }
+
+    #[test]
+    fn lines_end_after_each_lf() {
+        let index = LineIndex::from("one\r\ntwo\rthree\n");
+
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.line(0), Some("one\r\n"));
+        assert_eq!(index.line(1), Some("two\rthree\n"));
+        assert_eq!(index.line(2), Some(""));
+        assert_eq!(index.line(3), None);
+
+        // A lone CR does not end a line, and a CR before LF is the last character of its line.
+        assert_eq!(index.line_col(3, ColumnMode::Utf8), Some(at(0, 3)));
+        assert_eq!(index.line_col(5, ColumnMode::Utf8), Some(at(1, 0)));
+        assert_eq!(index.line_col(9, ColumnMode::Utf8), Some(at(1, 4)));
+        assert_eq!(index.offset(at(1, 4), ColumnMode::Utf8), Some(9));
+    }
+
+    #[test]
+    fn empty_text_has_one_empty_line() {
+        let index = LineIndex::from("");
+
+        assert_eq!(index.line_count(), 1);
+        assert_eq!(index.line_col(0, ColumnMode::Utf16), Some(at(0, 0)));
+        assert_eq!(index.offset(at(0, 0), ColumnMode::Utf16), Some(0));
+    }
+
+    #[test]
+    fn utf16_columns_count_code_units() {
+        let index = LineIndex::from("a\u{e9}\u{1f600}b\nx");
+
+        assert_eq!(index.line_col(3, ColumnMode::Utf8), Some(at(0, 3)));
+        assert_eq!(index.line_col(3, ColumnMode::Utf16), Some(at(0, 2)));
+        assert_eq!(index.line_col(7, ColumnMode::Utf16), Some(at(0, 4)));
+        assert_eq!(index.line_col(8, ColumnMode::Utf16), Some(at(0, 5)));
+
+        assert_eq!(index.offset(at(0, 4), ColumnMode::Utf16), Some(7));
+        assert_eq!(index.offset(at(0, 6), ColumnMode::Utf16), Some(9));
+    }
+
+    #[test]
+    fn columns_inside_a_character_are_rejected() {
+        let index = LineIndex::from("a\u{1f600}b");
+
+        // Column 2 falls between the two halves of the surrogate pair.
+        assert_eq!(index.offset(at(0, 2), ColumnMode::Utf16), None);
+        assert_eq!(index.offset(at(0, 3), ColumnMode::Utf16), Some(5));
+        assert_eq!(index.offset(at(0, 2), ColumnMode::Utf8), None);
+        assert_eq!(index.line_col(2, ColumnMode::Utf16), None);
+    }
+
+    #[test]
+    fn positions_past_the_end_are_rejected() {
+        let index = LineIndex::from("ab\ncd");
+
+        assert_eq!(index.line_col(5, ColumnMode::Utf8), Some(at(1, 2)));
+        assert_eq!(index.line_col(6, ColumnMode::Utf8), None);
+        assert_eq!(index.line_col(usize::MAX, ColumnMode::Utf16), None);
+
+        assert_eq!(index.offset(at(1, 3), ColumnMode::Utf8), None);
+        assert_eq!(index.offset(at(1, 3), ColumnMode::Utf16), None);
+        assert_eq!(index.offset(at(0, 3), ColumnMode::Utf8), Some(3));
+        assert_eq!(index.offset(at(0, 4), ColumnMode::Utf8), None);
+        assert_eq!(index.offset(at(2, 0), ColumnMode::Utf8), None);
+    }
+}
@@ -0,0 +1,9 @@
+use log::kv::{ToValue, Value};
+use crate::UTF8;
+
+impl ToValue for UTF8 {
+    /// Records the stored bytes as a borrowed string value.
+    fn to_value(&self) -> Value<'_> {
+        Value::from(&**self)
+    }
+}
@@ -0,0 +1,710 @@
+use napi::{bindgen_prelude::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue}, Status, ValueType, sys::*, *};
+use std::os::raw::c_char;
+use crate::{Blob, Str, UTF8};
+
+// These types own their storage outright, so they already cross threads into `ThreadsafeFunction`
+// callbacks and `AsyncTask`s as-is; no wrapper is needed as long as this keeps compiling.
+const _: () = {
+    const fn assert_transferable<T: Send + Sync + 'static>() { }
+    assert_transferable::<UTF8>();
+    assert_transferable::<Str<u16>>();
+    assert_transferable::<Blob>();
+};
+
+impl TypeName for UTF8 {
+    fn type_name() -> &'static str {
+        "String"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::String
+    }
+}
+
+/// Mismatches are reported as errors, never thrown: napi-rs probes every `Either` arm and `Option` with
+/// `validate`, and a pending exception would fail the next probe.
+impl ValidateNapiValue for UTF8 {
+    unsafe fn validate(env: napi_env, value: napi_value) -> Result<napi_value> {
+        let mut kind = -1;
+        let status = napi_typeof(env, value, &mut kind);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to detect value type"));
+        }
+
+        if kind != ValueType::String as i32 && uint8_array_bytes(env, value)?.is_none() {
+            return Err(Error::new(Status::StringExpected, "Expected a string, Buffer or Uint8Array"));
+        }
+
+        Ok(std::ptr::null_mut())
+    }
+}
+
+unsafe fn expect_string(env: napi_env, value: napi_value) -> Result<napi_value> {
+    let mut kind = -1;
+    let status = napi_typeof(env, value, &mut kind);
+
+    if status != 0 /* napi_ok */ {
+        return Err(Error::new(Status::from(status), "Failed to detect value type"));
+    }
+
+    if kind != ValueType::String as i32 {
+        return Err(Error::new(Status::StringExpected, "Expected a string"));
+    }
+
+    Ok(std::ptr::null_mut())
+}
+
+/// Invalid input (bad UTF-8, unrepresentable values) becomes `InvalidArg`; anything else a `GenericFailure`.
+impl From<crate::Error> for Error {
+    fn from(error: crate::Error) -> Self {
+        let status = match error {
+            crate::Error::Utf8(_) | crate::Error::Conversion(_) => Status::InvalidArg,
+            _ => Status::GenericFailure,
+        };
+
+        Error::new(status, error.to_string())
+    }
+}
+
+/// Accepts a string, or a `Buffer`/`Uint8Array` holding valid UTF-8.
+impl FromNapiValue for UTF8 {
+    unsafe fn from_napi_value(env: napi_env, value: napi_value) -> Result<Self> {
+        let mut needed = 0;
+        let status = napi_get_value_string_utf8(env, value, std::ptr::null_mut(), 0, &mut needed);
+
+        if status == sys::Status::napi_string_expected && let Some(bytes) = uint8_array_bytes(env, value)? {
+            return std::str::from_utf8(bytes)
+                .map_err(|error| Error::from(crate::Error::from(error)))
+                .map(UTF8::from)
+        }
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to get string size"));
+        }
+
+        if needed <= 256 {
+            let mut written = 0;
+            let mut buf     = [0u8; 257];
+            let status = napi_get_value_string_utf8(env, value, buf.as_mut_ptr() as *mut c_char, needed + 1, &mut written);
+
+            if status != 0 /* napi_ok */ {
+                return Err(Error::new(Status::from(status), "Failed stack read"));
+            }
+
+            return std::str::from_utf8(&buf[..written])
+                .map_err(|error| Error::from(crate::Error::from(error)))
+                .map(UTF8::from)
+        }
+
+        let mut written = 0;
+        let mut vec     = Vec::with_capacity(needed + 1);
+
+        let status = napi_get_value_string_utf8(env, value, vec.as_mut_ptr() as *mut c_char, needed + 1, &mut written);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed heap read"));
+        }
+
+        vec.set_len(written);
+        String::from_utf8(vec)
+            .map_err(|error| Error::from(crate::Error::from(error)))
+            .map(UTF8::from)
+    }
+}
+
+impl ToNapiValue for &UTF8 {
+    unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value> {
+        let mut result = std::ptr::null_mut();
+        let status     = napi_create_string_utf8(env, value.as_ptr() as *const c_char, value.len() as _, &mut result);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to create string"));
+        }
+
+        Ok(result)
+    }
+}
+
+/// With `napi-external-strings`, an all-ASCII `Boxed` value is handed to V8 as an external Latin-1 string
+/// instead of being copied; everything else goes through `napi_create_string_utf8`.
+impl ToNapiValue for UTF8 {
+    unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value> {
+        match value {
+            #[cfg(feature = "napi-external-strings")]
+            UTF8::Boxed { buf, len } if buf[..len].is_ascii() => {
+                let (ptr, size) = (buf.as_ptr(), buf.len());
+                std::mem::forget(buf);
+
+                let mut result = std::ptr::null_mut();
+                let mut copied = false;
+                let status     = node_api_create_external_string_latin1(
+                    env, ptr as *const c_char, len, Some(drop_external::<u8>), size as *mut std::ffi::c_void, &mut result, &mut copied,
+                );
+
+                if status != 0 /* napi_ok */ {
+                    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr as *mut u8, size)));
+                    return Err(Error::new(Status::from(status), "Failed to create external string"));
+                }
+
+                Ok(result)
+            }
+
+            value => ToNapiValue::to_napi_value(env, &value)
+        }
+    }
+}
+
+/// Finalizer for external strings and buffers: rebuilds the `Box<[E]>` whose data pointer and length were handed to V8.
+///
+/// It also runs when V8 chose to copy instead, so the buffer is released either way. If creation fails the
+/// finalizer never runs and the engine holds no reference, so the caller frees the buffer itself.
+unsafe extern "C" fn drop_external<E>(_env: napi_env, data: *mut std::ffi::c_void, size: *mut std::ffi::c_void) {
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(data as *mut E, size as usize)));
+}
+
+impl TypeName for Str<u16> {
+    fn type_name() -> &'static str {
+        "String"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::String
+    }
+}
+
+impl ValidateNapiValue for Str<u16> {
+    unsafe fn validate(env: napi_env, value: napi_value) -> Result<napi_value> {
+        expect_string(env, value)
+    }
+}
+
+/// Reads the string as UTF-16 code units, skipping the UTF-8 transcode for two-byte V8 strings.
+/// Use [`Str::to_utf8`] once a `UTF8` is actually needed.
+impl FromNapiValue for Str<u16> {
+    unsafe fn from_napi_value(env: napi_env, value: napi_value) -> Result<Self> {
+        let mut needed = 0;
+        let status = napi_get_value_string_utf16(env, value, std::ptr::null_mut(), 0, &mut needed);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to get string size"));
+        }
+
+        if needed <= 256 {
+            let mut written = 0;
+            let mut buf     = [0u16; 257];
+            let status = napi_get_value_string_utf16(env, value, buf.as_mut_ptr(), needed + 1, &mut written);
+
+            if status != 0 /* napi_ok */ {
+                return Err(Error::new(Status::from(status), "Failed stack read"));
+            }
+
+            return Ok(Str::from(&buf[..written]))
+        }
+
+        let mut written = 0;
+        let mut vec     = Vec::with_capacity(needed + 1);
+
+        let status = napi_get_value_string_utf16(env, value, vec.as_mut_ptr(), needed + 1, &mut written);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed heap read"));
+        }
+
+        vec.set_len(written);
+        Ok(Str::Boxed { buf: vec.into_boxed_slice(), len: written })
+    }
+}
+
+impl ToNapiValue for &Str<u16> {
+    unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value> {
+        let mut result = std::ptr::null_mut();
+        let status     = napi_create_string_utf16(env, value.as_ptr(), value.len() as _, &mut result);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to create string"));
+        }
+
+        Ok(result)
+    }
+}
+
+/// With `napi-external-strings`, `Boxed` values become external UTF-16 strings instead of being copied.
+impl ToNapiValue for Str<u16> {
+    unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value> {
+        match value {
+            #[cfg(feature = "napi-external-strings")]
+            Str::Boxed { buf, len } => {
+                let (ptr, size) = (buf.as_ptr(), buf.len());
+                std::mem::forget(buf);
+
+                let mut result = std::ptr::null_mut();
+                let mut copied = false;
+                let status     = node_api_create_external_string_utf16(
+                    env, ptr, len, Some(drop_external::<u16>), size as *mut std::ffi::c_void, &mut result, &mut copied,
+                );
+
+                if status != 0 /* napi_ok */ {
+                    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr as *mut u16, size)));
+                    return Err(Error::new(Status::from(status), "Failed to create external string"));
+                }
+
+                Ok(result)
+            }
+
+            value => ToNapiValue::to_napi_value(env, &value)
+        }
+    }
+}
+
+// `Str<u8>` is the same type as `UTF8`, which already maps to JavaScript strings, so binary payloads
+// cross the boundary as `Blob`, which maps to `Buffer`.
+
+impl TypeName for Blob {
+    fn type_name() -> &'static str {
+        "Buffer"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Object
+    }
+}
+
+impl ValidateNapiValue for Blob {
+    unsafe fn validate(env: napi_env, value: napi_value) -> Result<napi_value> {
+        if uint8_array_bytes(env, value)?.is_none() {
+            return Err(Error::new(Status::InvalidArg, "Expected a Buffer or Uint8Array"));
+        }
+
+        Ok(std::ptr::null_mut())
+    }
+}
+
+/// Accepts a `Buffer` or `Uint8Array`. The contents are always copied: small payloads land inline,
+/// larger ones in a fresh `Boxed` allocation, since JavaScript-owned memory can't be adopted.
+impl FromNapiValue for Blob {
+    unsafe fn from_napi_value(env: napi_env, value: napi_value) -> Result<Self> {
+        match uint8_array_bytes(env, value)? {
+            Some(bytes) => Ok(Blob(Str::from(bytes))),
+            None        => Err(Error::new(Status::InvalidArg, "Expected a Buffer or Uint8Array")),
+        }
+    }
+}
+
+/// Borrows the contents of a `Buffer` or `Uint8Array`, or returns `None` for any other value.
+unsafe fn uint8_array_bytes<'a>(env: napi_env, value: napi_value) -> Result<Option<&'a [u8]>> {
+    let mut is_typedarray = false;
+    let status = napi_is_typedarray(env, value, &mut is_typedarray);
+
+    if status != 0 /* napi_ok */ {
+        return Err(Error::new(Status::from(status), "Failed to inspect value"));
+    }
+
+    if !is_typedarray {
+        return Ok(None);
+    }
+
+    let mut kind = -1;
+    let mut len  = 0;
+    let mut data = std::ptr::null_mut();
+    let status = napi_get_typedarray_info(env, value, &mut kind, &mut len, &mut data, std::ptr::null_mut(), std::ptr::null_mut());
+
+    if status != 0 /* napi_ok */ {
+        return Err(Error::new(Status::from(status), "Failed to get typed array info"));
+    }
+
+    match (kind, len) {
+        (TypedarrayType::uint8_array, 0) => Ok(Some(&[])),
+        (TypedarrayType::uint8_array, _) => Ok(Some(std::slice::from_raw_parts(data as *const u8, len))),
+        _                                => Ok(None),
+    }
+}
+
+/// Produces a `Buffer`. `Boxed` payloads hand their allocation to JavaScript as an external buffer,
+/// falling back to a copy where external buffers are disallowed; inline payloads are copied.
+impl ToNapiValue for Blob {
+    unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value> {
+        let mut result = std::ptr::null_mut();
+
+        if let Str::Boxed { buf, len } = value.0 {
+            let size = buf.len();
+            let ptr  = Box::into_raw(buf) as *mut u8;
+            let status = napi_create_external_buffer(env, len, ptr as *mut _, Some(drop_external::<u8>), size as *mut _, &mut result);
+
+            if status == 0 /* napi_ok */ {
+                return Ok(result);
+            }
+
+            let buf = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, size));
+            if status != sys::Status::napi_no_external_buffers_allowed {
+                return Err(Error::new(Status::from(status), "Failed to create external buffer"));
+            }
+
+            return to_buffer_copy(env, &buf[..len]);
+        }
+
+        to_buffer_copy(env, value.0.elements())
+    }
+}
+
+unsafe fn to_buffer_copy(env: napi_env, bytes: &[u8]) -> Result<napi_value> {
+    let mut result = std::ptr::null_mut();
+    let status = napi_create_buffer_copy(env, bytes.len(), bytes.as_ptr() as *const _, std::ptr::null_mut(), &mut result);
+
+    if status != 0 /* napi_ok */ {
+        return Err(Error::new(Status::from(status), "Failed to create buffer"));
+    }
+
+    Ok(result)
+}
+
+/// Reads the property `key` of `object` straight into a `UTF8`, without allocating a `CString` for the key.
+///
+/// # Safety
+///
+/// `env` and `object` must be valid handles for the current callback scope.
+pub unsafe fn get_named_property_utf8(env: napi_env, object: napi_value, key: &std::ffi::CStr) -> Result<UTF8> {
+    let mut value = std::ptr::null_mut();
+    let status = napi_get_named_property(env, object, key.as_ptr(), &mut value);
+
+    if status != 0 /* napi_ok */ {
+        return Err(Error::new(Status::from(status), "Failed to get named property"));
+    }
+
+    UTF8::from_napi_value(env, value)
+}
+
+/// String accessors on [`JsObject`] that land directly in `UTF8` storage.
+///
+/// Keys are JavaScript strings, so a key built once with `Env::create_string` is reused across lookups
+/// instead of being copied into a `CString` on every call. From raw handles, use [`get_named_property_utf8`].
+pub trait JsObjectExt {
+    /// Reads the string property `key`.
+    fn get_utf8(&self, key: &JsString) -> Result<UTF8>;
+
+    /// Reads the string property `key`, mapping `undefined` and `null` to `None`.
+    fn get_utf8_opt(&self, key: &JsString) -> Result<Option<UTF8>>;
+
+    /// Collects the enumerable property names, as reported by `napi_get_property_names`.
+    fn property_names_utf8(&self) -> Result<Vec<UTF8>>;
+}
+
+impl JsObjectExt for JsObject {
+    fn get_utf8(&self, key: &JsString) -> Result<UTF8> {
+        UTF8::from_unknown(self.get_property_unchecked::<_, JsUnknown>(key)?)
+    }
+
+    fn get_utf8_opt(&self, key: &JsString) -> Result<Option<UTF8>> {
+        Option::<UTF8>::from_unknown(self.get_property_unchecked::<_, JsUnknown>(key)?)
+    }
+
+    fn property_names_utf8(&self) -> Result<Vec<UTF8>> {
+        let names   = self.get_property_names()?;
+        let len     = names.get_array_length_unchecked()?;
+        let mut out = Vec::with_capacity(len as usize);
+
+        for index in 0..len {
+            out.push(UTF8::from_unknown(names.get_element_unchecked::<JsUnknown>(index)?)?);
+        }
+
+        Ok(out)
+    }
+}
+
+/// A JavaScript `string[]` read into `UTF8` values.
+///
+/// `Vec<UTF8>` already converts through napi's generic array impls; this wrapper reads the array in a single
+/// pre-sized pass, names the offending index on failure, and builds from any iterator of string-likes.
+#[derive(Clone, Debug, Default)]
+pub struct Utf8Array(pub Vec<UTF8>);
+
+impl<T: Into<UTF8>> FromIterator<T> for Utf8Array {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Utf8Array(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<Vec<UTF8>> for Utf8Array {
+    fn from(value: Vec<UTF8>) -> Self {
+        Utf8Array(value)
+    }
+}
+
+impl From<Utf8Array> for Vec<UTF8> {
+    fn from(value: Utf8Array) -> Self {
+        value.0
+    }
+}
+
+impl TypeName for Utf8Array {
+    fn type_name() -> &'static str {
+        "Array<string>"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Object
+    }
+}
+
+impl ValidateNapiValue for Utf8Array {
+    unsafe fn validate(env: napi_env, value: napi_value) -> Result<napi_value> {
+        let mut is_array = false;
+        let status = napi_is_array(env, value, &mut is_array);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to inspect value"));
+        }
+
+        if !is_array {
+            return Err(Error::new(Status::ArrayExpected, "Expected an array of strings"));
+        }
+
+        Ok(std::ptr::null_mut())
+    }
+}
+
+impl FromNapiValue for Utf8Array {
+    unsafe fn from_napi_value(env: napi_env, value: napi_value) -> Result<Self> {
+        let mut is_array = false;
+        let status = napi_is_array(env, value, &mut is_array);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to inspect value"));
+        }
+
+        if !is_array {
+            return Err(Error::new(Status::ArrayExpected, "Expected an array of strings"));
+        }
+
+        let mut len = 0;
+        let status = napi_get_array_length(env, value, &mut len);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to get array length"));
+        }
+
+        let mut vec = Vec::with_capacity(len as usize);
+
+        for index in 0..len {
+            let mut element = std::ptr::null_mut();
+            let status = napi_get_element(env, value, index, &mut element);
+
+            if status != 0 /* napi_ok */ {
+                return Err(Error::new(Status::from(status), format!("Failed to get element {index}")));
+            }
+
+            let element = UTF8::from_napi_value(env, element)
+                .map_err(|error| Error::new(error.status, format!("Element {index}: {}", error.reason)))?;
+
+            vec.push(element);
+        }
+
+        Ok(Utf8Array(vec))
+    }
+}
+
+impl ToNapiValue for Utf8Array {
+    unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value> {
+        let mut array = std::ptr::null_mut();
+        let status = napi_create_array_with_length(env, value.0.len(), &mut array);
+
+        if status != 0 /* napi_ok */ {
+            return Err(Error::new(Status::from(status), "Failed to create array"));
+        }
+
+        for (index, element) in value.0.into_iter().enumerate() {
+            let status = napi_set_element(env, array, index as u32, UTF8::to_napi_value(env, element)?);
+
+            if status != 0 /* napi_ok */ {
+                return Err(Error::new(Status::from(status), format!("Failed to set element {index}")));
+            }
+        }
+
+        Ok(array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! A minimal stand-in for the Node-API host, so the conversions can run without Node. Values are
+    //! pointers to a [`Mock`], and a thrown exception fails every later call, as in V8.
+
+    use super::*;
+    use napi::bindgen_prelude::Either;
+    use std::cell::Cell;
+
+    enum Mock {
+        Number(u32),
+        Text(&'static str),
+    }
+
+    thread_local! {
+        static PENDING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    fn value(mock: &'static Mock) -> napi_value {
+        mock as *const Mock as napi_value
+    }
+
+    unsafe fn mock<'a>(value: napi_value) -> &'a Mock {
+        &*(value as *const Mock)
+    }
+
+    fn pending() -> bool {
+        PENDING.with(Cell::get)
+    }
+
+    /// Copies `units` into a napi-style output buffer: the length when `buf` is null, otherwise as much
+    /// as fits before the terminator.
+    unsafe fn write_out<T: Copy + Default>(units: &[T], buf: *mut T, size: usize, result: *mut usize) -> napi_status {
+        if buf.is_null() {
+            *result = units.len();
+            return 0;
+        }
+
+        let written = units.len().min(size.saturating_sub(1));
+        std::ptr::copy_nonoverlapping(units.as_ptr(), buf, written);
+        *buf.add(written) = T::default();
+        *result = written;
+        0
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_typeof(_env: napi_env, value: napi_value, result: *mut napi_valuetype) -> napi_status {
+        if pending() {
+            return sys::Status::napi_pending_exception;
+        }
+
+        *result = match mock(value) {
+            Mock::Number(_) => sys::ValueType::napi_number,
+            Mock::Text(_)   => sys::ValueType::napi_string,
+        };
+        0
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_is_typedarray(_env: napi_env, _value: napi_value, result: *mut bool) -> napi_status {
+        if pending() {
+            return sys::Status::napi_pending_exception;
+        }
+
+        *result = false;
+        0
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_get_typedarray_info(
+        _env: napi_env, _value: napi_value, _kind: *mut napi_typedarray_type, _len: *mut usize,
+        _data: *mut *mut std::ffi::c_void, _buffer: *mut napi_value, _offset: *mut usize,
+    ) -> napi_status {
+        sys::Status::napi_invalid_arg
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_throw_type_error(_env: napi_env, _code: *const c_char, _msg: *const c_char) -> napi_status {
+        PENDING.with(|pending| pending.set(true));
+        0
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_get_value_uint32(_env: napi_env, value: napi_value, result: *mut u32) -> napi_status {
+        match mock(value) {
+            _ if pending()     => sys::Status::napi_pending_exception,
+            Mock::Number(n) => { *result = *n; 0 }
+            Mock::Text(_)   => sys::Status::napi_number_expected,
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_get_value_string_utf8(
+        _env: napi_env, value: napi_value, buf: *mut c_char, size: usize, result: *mut usize,
+    ) -> napi_status {
+        match mock(value) {
+            _ if pending()     => sys::Status::napi_pending_exception,
+            Mock::Text(text) => write_out(text.as_bytes(), buf as *mut u8, size, result),
+            Mock::Number(_)  => sys::Status::napi_string_expected,
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_get_value_string_utf16(
+        _env: napi_env, value: napi_value, buf: *mut u16, size: usize, result: *mut usize,
+    ) -> napi_status {
+        match mock(value) {
+            _ if pending()     => sys::Status::napi_pending_exception,
+            Mock::Text(text) => write_out(&text.encode_utf16().collect::<Vec<_>>(), buf, size, result),
+            Mock::Number(_)  => sys::Status::napi_string_expected,
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_is_array(_env: napi_env, _value: napi_value, result: *mut bool) -> napi_status {
+        if pending() {
+            return sys::Status::napi_pending_exception;
+        }
+
+        *result = false;
+        0
+    }
+
+    // Only reached when a validator hands back a rejected promise, which none of these mocks do.
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_get_named_property(
+        _env: napi_env, _object: napi_value, _name: *const c_char, _result: *mut napi_value,
+    ) -> napi_status {
+        sys::Status::napi_generic_failure
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_create_function(
+        _env: napi_env, _name: *const c_char, _len: usize, _cb: napi_callback, _data: *mut std::ffi::c_void,
+        _result: *mut napi_value,
+    ) -> napi_status {
+        sys::Status::napi_generic_failure
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn napi_call_function(
+        _env: napi_env, _recv: napi_value, _func: napi_value, _argc: usize, _argv: *const napi_value,
+        _result: *mut napi_value,
+    ) -> napi_status {
+        sys::Status::napi_generic_failure
+    }
+
+    #[test]
+    fn either_probes_past_a_failed_utf8_arm() {
+        static NUMBER: Mock = Mock::Number(7);
+        let parsed = unsafe { Either::<UTF8, u32>::from_napi_value(std::ptr::null_mut(), value(&NUMBER)) };
+
+        assert!(matches!(parsed, Ok(Either::B(7))));
+        assert!(!pending());
+    }
+
+    #[test]
+    fn either_reads_strings_through_the_utf8_arm() {
+        static TEXT: Mock = Mock::Text("h\u{e9}llo");
+        let parsed = unsafe { Either::<u32, UTF8>::from_napi_value(std::ptr::null_mut(), value(&TEXT)) };
+
+        assert!(matches!(parsed, Ok(Either::B(text)) if &*text == "h\u{e9}llo"));
+    }
+
+    #[test]
+    fn validate_reports_without_throwing() {
+        static NUMBER: Mock = Mock::Number(7);
+        let error = unsafe { <Str<u16> as ValidateNapiValue>::validate(std::ptr::null_mut(), value(&NUMBER)) };
+
+        assert_eq!(error.map_err(|error| error.status), Err(Status::StringExpected));
+        assert!(!pending());
+    }
+
+    #[test]
+    fn blob_and_array_validate_report_without_throwing() {
+        static NUMBER: Mock = Mock::Number(7);
+        let blob  = unsafe { <Blob as ValidateNapiValue>::validate(std::ptr::null_mut(), value(&NUMBER)) };
+        let array = unsafe { <Utf8Array as ValidateNapiValue>::validate(std::ptr::null_mut(), value(&NUMBER)) };
+
+        assert_eq!(blob.map_err(|error| error.status), Err(Status::InvalidArg));
+        assert_eq!(array.map_err(|error| error.status), Err(Status::ArrayExpected));
+        assert!(!pending());
+    }
+}
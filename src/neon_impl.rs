@@ -0,0 +1,34 @@
+use neon::{prelude::*, sys::bindings as napi};
+use crate::UTF8;
+
+impl UTF8 {
+    /// Reads a Neon `JsString`. Strings up to 256 bytes are copied straight into an inline bucket,
+    /// skipping the `String` that `JsString::value` allocates; longer ones adopt that `String` as `Boxed`.
+    ///
+    /// A failed read throws a JavaScript `Error` instead of panicking across the FFI boundary.
+    pub fn from_js_string<'a, C: Context<'a>>(cx: &mut C, string: Handle<JsString>) -> NeonResult<Self> {
+        let len = string.size(cx);
+
+        if len > 256 {
+            return Ok(Self::from(string.value(cx)));
+        }
+
+        let mut written = 0;
+        let mut buf     = [0u8; 257];
+
+        let status = unsafe {
+            napi::get_value_string_utf8(cx.to_raw(), string.to_raw(), buf.as_mut_ptr().cast(), len + 1, &mut written)
+        };
+
+        if status.is_err() {
+            return cx.throw_error("Failed to read string");
+        }
+
+        Ok(Self::from(&buf[..written]))
+    }
+
+    /// Creates a Neon `JsString` from the stored bytes.
+    pub fn to_js_string<'a, C: Context<'a>>(&self, cx: &mut C) -> Handle<'a, JsString> {
+        JsString::new(cx, self)
+    }
+}
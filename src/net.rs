@@ -0,0 +1,26 @@
+use std::net::{AddrParseError, IpAddr, SocketAddr};
+
+use crate::UTF8;
+
+impl UTF8 {
+    /// Formats `addr` through a stack buffer; the result always fits an inline bucket
+    /// (at most 15 bytes for IPv4, 39 for IPv6).
+    pub fn from_ip(addr: impl Into<IpAddr>) -> Self {
+        UTF8::from_fmt(format_args!("{}", addr.into()))
+    }
+
+    /// Formats `addr` with its port, e.g. `127.0.0.1:8080` or `[::1]:443`, staying inline.
+    pub fn from_socket_addr(addr: impl Into<SocketAddr>) -> Self {
+        UTF8::from_fmt(format_args!("{}", addr.into()))
+    }
+
+    /// Parses the text as an IPv4 or IPv6 address.
+    pub fn parse_ip(&self) -> Result<IpAddr, AddrParseError> {
+        self.text().parse()
+    }
+
+    /// Parses the text as a socket address.
+    pub fn parse_socket_addr(&self) -> Result<SocketAddr, AddrParseError> {
+        self.text().parse()
+    }
+}
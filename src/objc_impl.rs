@@ -0,0 +1,27 @@
+use objc2::rc::Retained;
+use objc2_foundation::{NSString, NSUTF8StringEncoding};
+use crate::UTF8;
+
+impl UTF8 {
+    /// Reads an `NSString`. Strings up to 256 UTF-8 bytes are encoded straight into a stack buffer
+    /// with `getCString:maxLength:encoding:`; longer ones go through `-[NSString UTF8String]`.
+    pub fn from_ns_string(string: &NSString) -> Self {
+        let len = string.len();
+
+        if len <= 256 {
+            let mut buf = [0u8; 257];
+            let ptr     = std::ptr::NonNull::from(&mut buf).cast();
+
+            if unsafe { string.getCString_maxLength_encoding(ptr, buf.len(), NSUTF8StringEncoding) } {
+                return Self::from(&buf[..len]);
+            }
+        }
+
+        Self::from(string.to_string())
+    }
+
+    /// Creates an `NSString` by copying the stored bytes.
+    pub fn to_ns_string(&self) -> Retained<NSString> {
+        NSString::from_str(self)
+    }
+}
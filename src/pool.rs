@@ -0,0 +1,131 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::UTF8;
+
+/// Hands out pool ids. Two pools only share an id after the counter wraps, 2^32 pools later.
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+fn next_id() -> u32 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A request-scoped string arena.
+///
+/// [`alloc`](Self::alloc) copies text into one growing buffer and returns a [`PoolStr`] handle.
+/// [`reset`](Self::reset) drops everything at once but keeps the buffer, so once a pool has grown to its
+/// steady-state size, later requests or frames allocate nothing. Every reset starts a new generation.
+/// Handles from an earlier generation, or from another pool, are caught by [`try_get`](Self::try_get), and by
+/// [`get`](Self::get) in debug builds. A clone is a separate pool with its own id.
+#[derive(Debug)]
+pub struct Pool {
+    text:       String,
+    id:         u32,
+    generation: u32,
+}
+
+/// A handle to text stored in a [`Pool`], valid until the pool's next [`reset`](Pool::reset).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoolStr {
+    start:      usize,
+    len:        usize,
+    pool:       u32,
+    generation: u32,
+}
+
+impl PoolStr {
+    /// Returns the length of the text in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Default for Pool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Pool {
+    /// Copies the text into a new pool with its own id, so handles from `self` are not valid on the clone.
+    fn clone(&self) -> Self {
+        Self { text: self.text.clone(), id: next_id(), generation: 0 }
+    }
+}
+
+impl Pool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self { text: String::new(), id: next_id(), generation: 0 }
+    }
+
+    /// Creates an empty pool with room for `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { text: String::with_capacity(capacity), id: next_id(), generation: 0 }
+    }
+
+    /// Copies `text` into the pool.
+    pub fn alloc(&mut self, text: &str) -> PoolStr {
+        let start = self.text.len();
+        self.text.push_str(text);
+        PoolStr { start, len: text.len(), pool: self.id, generation: self.generation }
+    }
+
+    /// Returns the text behind `handle`.
+    ///
+    /// Panics in debug builds if `handle` came from another pool or was allocated before the last
+    /// [`reset`](Self::reset). Release builds skip the checks and may return unrelated text or `""` for such a handle.
+    pub fn get(&self, handle: PoolStr) -> &str {
+        debug_assert_eq!(handle.pool, self.id, "PoolStr used with a Pool it did not come from");
+        debug_assert_eq!(handle.generation, self.generation, "PoolStr used after its Pool was reset");
+        self.text.get(handle.start..handle.start + handle.len).unwrap_or("")
+    }
+
+    /// Returns the text behind `handle`, or `None` if it came from another pool or was allocated before the
+    /// last reset.
+    pub fn try_get(&self, handle: PoolStr) -> Option<&str> {
+        (handle.pool == self.id && handle.generation == self.generation).then(|| self.get(handle))
+    }
+
+    /// Copies the text behind `handle` into a `UTF8`, which stays inline up to 256 bytes.
+    pub fn to_utf8(&self, handle: PoolStr) -> UTF8 {
+        UTF8::from(self.get(handle))
+    }
+
+    /// Drops all text, keeping the buffer, and invalidates every handle handed out so far.
+    pub fn reset(&mut self) {
+        self.text.clear();
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns the number of bytes in use.
+    pub fn used(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns the number of bytes the pool can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.text.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_are_rejected_by_other_pools() {
+        let mut first  = Pool::new();
+        let mut second = Pool::default();
+        let handle = first.alloc("first");
+        second.alloc("second");
+
+        assert_eq!(first.try_get(handle), Some("first"));
+        assert_eq!(second.try_get(handle), None);
+        assert_eq!(first.clone().try_get(handle), None);
+    }
+}
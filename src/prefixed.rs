@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::UTF8;
+
+const INLINE: usize = 12;
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE] },
+    Heap   { prefix: [u8; 4], buf: Box<str> },
+}
+
+/// An immutable string in the Umbra ("German string") style: up to 12 bytes inline, otherwise the first
+/// 4 bytes are kept next to the heap pointer.
+///
+/// Equality and ordering look at the length and that prefix first, so most comparisons between long
+/// strings finish without touching the heap. The value is 24 bytes, like `String`; the original 16-byte
+/// layout needs a thin pointer, which would take `unsafe`.
+#[derive(Clone)]
+pub struct PrefixedUTF8(Repr);
+
+impl PrefixedUTF8 {
+    /// Copies `text`, inline when it is at most 12 bytes.
+    pub fn new(text: &str) -> Self {
+        if text.len() <= INLINE {
+            let mut buf = [0; INLINE];
+            buf[..text.len()].copy_from_slice(text.as_bytes());
+            return Self(Repr::Inline { len: text.len() as u8, buf });
+        }
+
+        Self::from(String::from(text))
+    }
+
+    /// Returns the text.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Inline { len, buf } => std::str::from_utf8(&buf[..*len as usize]).expect("copied from a str"),
+            Repr::Heap   { buf, .. }  => buf,
+        }
+    }
+
+    /// Returns the length in bytes.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Repr::Inline { len, .. } => *len as usize,
+            Repr::Heap   { buf, .. } => buf.len(),
+        }
+    }
+
+    /// Returns `true` if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the first 4 bytes, zero-padded for shorter strings.
+    pub fn prefix(&self) -> [u8; 4] {
+        match &self.0 {
+            Repr::Inline { buf, .. }    => [buf[0], buf[1], buf[2], buf[3]],
+            Repr::Heap   { prefix, .. } => *prefix,
+        }
+    }
+
+    /// Returns `true` if the text is stored inline.
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline { .. })
+    }
+
+    /// Copies the text into a `UTF8`.
+    pub fn to_utf8(&self) -> UTF8 {
+        UTF8::from(self.as_str())
+    }
+}
+
+impl Default for PrefixedUTF8 {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl std::ops::Deref for PrefixedUTF8 {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for PrefixedUTF8 {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for PrefixedUTF8 {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Repr::Inline { len: a, buf: x }, Repr::Inline { len: b, buf: y }) => a == b && x == y,
+            _ => self.len() == other.len() && self.prefix() == other.prefix() && self.as_str() == other.as_str(),
+        }
+    }
+}
+impl Eq for PrefixedUTF8 { }
+
+impl PartialOrd for PrefixedUTF8 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PrefixedUTF8 {
+    /// Zero padding keeps prefix order consistent with byte order, so a differing prefix decides on its own.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.prefix().cmp(&other.prefix()) {
+            Ordering::Equal => self.as_str().cmp(other.as_str()),
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialEq<str> for PrefixedUTF8 {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PrefixedUTF8 {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for PrefixedUTF8 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Display for PrefixedUTF8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for PrefixedUTF8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl From<&str> for PrefixedUTF8 {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for PrefixedUTF8 {
+    /// Short strings move inline; longer ones keep the `String`'s buffer.
+    fn from(text: String) -> Self {
+        if text.len() <= INLINE {
+            return Self::new(&text);
+        }
+
+        let bytes  = text.as_bytes();
+        let prefix = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        Self(Repr::Heap { prefix, buf: text.into_boxed_str() })
+    }
+}
+
+impl From<&UTF8> for PrefixedUTF8 {
+    fn from(text: &UTF8) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<UTF8> for PrefixedUTF8 {
+    /// Heap buffers are handed over; inline values are copied.
+    fn from(text: UTF8) -> Self {
+        match text {
+            UTF8::Boxed { .. } => Self::from(String::from(text)),
+            _ => Self::from(&text),
+        }
+    }
+}
+
+impl From<PrefixedUTF8> for UTF8 {
+    fn from(text: PrefixedUTF8) -> Self {
+        match text.0 {
+            Repr::Heap { buf, .. } => UTF8::from(String::from(buf)),
+            Repr::Inline { .. } => text.to_utf8(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use pyo3::{prelude::*, types::PyString};
+use std::{borrow::Cow, convert::Infallible};
+use crate::UTF8;
+
+/// Borrows the interpreter's cached UTF-8 view where possible, so short strings are copied exactly once,
+/// straight into an inline bucket.
+impl FromPyObject<'_, '_> for UTF8 {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> Result<Self, Self::Error> {
+        Ok(match obj.cast::<PyString>()?.to_cow()? {
+            Cow::Borrowed(s) => UTF8::from(s),
+            Cow::Owned(s)    => UTF8::from(s),
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &UTF8 {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error  = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(PyString::new(py, self))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for UTF8 {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error  = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&self).into_pyobject(py)
+    }
+}
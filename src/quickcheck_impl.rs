@@ -0,0 +1,59 @@
+use quickcheck::{Arbitrary, Gen};
+use crate::{Str, UTF8};
+
+/// Byte lengths at which the bucket changes; generation targets one of them so every variant gets exercised.
+/// `From` never picks `B32`, so values generated for that target are laid out in a `B32` by hand.
+const TARGETS: [usize; 8] = [0, 8, 16, 32, 64, 128, 256, 512];
+
+impl Arbitrary for UTF8 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let target = *g.choose(&TARGETS).unwrap();
+        let len    = usize::arbitrary(g) % (target + 1);
+        let mut s  = String::with_capacity(len);
+
+        loop {
+            let c = char::arbitrary(g);
+            if s.len() + c.len_utf8() > len { break }
+            s.push(c);
+        }
+
+        if target == 32 && !s.is_empty() {
+            let mut buf = [0u8; 32];
+            buf[..s.len()].copy_from_slice(s.as_bytes());
+            return Str::B32 { buf, len: s.len() as u8 };
+        }
+
+        UTF8::from(s)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(String::from(self.as_ref() as &str).shrink().map(UTF8::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+
+    use crate::{Str, UTF8};
+
+    #[test]
+    fn generation_reaches_b32() {
+        let mut g = Gen::new(64);
+        assert!((0..1000).any(|_| matches!(UTF8::arbitrary(&mut g), Str::B32 { .. })));
+    }
+
+    quickcheck! {
+        fn clone_equals_original(value: UTF8) -> bool {
+            value.clone() == value
+        }
+
+        fn generated_values_are_valid(value: UTF8) -> bool {
+            value.check_invariants().is_ok() && value.check_utf8().is_ok()
+        }
+
+        fn equality_follows_text(a: UTF8, b: UTF8) -> bool {
+            (a == b) == (*a == *b) && UTF8::from(&*a) == a
+        }
+    }
+}
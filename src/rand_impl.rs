@@ -0,0 +1,31 @@
+use rand::distr::{Alphanumeric, Distribution};
+use rand::{Rng, RngExt};
+
+use crate::UTF8;
+
+/// Samples `UTF8` strings of the given length from `[A-Za-z0-9]`, like `Alphanumeric.sample_string`.
+#[derive(Clone, Copy, Debug)]
+pub struct AlphanumericUtf8(pub usize);
+
+impl Distribution<UTF8> for AlphanumericUtf8 {
+    /// Fills a stack buffer when the result fits in an inline bucket.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UTF8 {
+        let len = self.0;
+
+        if len <= 256 {
+            let mut buf = [0u8; 256];
+            buf[..len].iter_mut().for_each(|b| *b = rng.sample(Alphanumeric));
+            return UTF8::from(&buf[..len]);
+        }
+
+        let bytes = (0..len).map(|_| rng.sample(Alphanumeric)).collect::<Box<[u8]>>();
+        UTF8::Boxed { buf: bytes, len }
+    }
+}
+
+impl UTF8 {
+    /// Generates `len` random ASCII letters and digits, e.g. for request IDs and nonces.
+    pub fn random_alphanumeric<R: Rng + ?Sized>(len: usize, rng: &mut R) -> Self {
+        AlphanumericUtf8(len).sample(rng)
+    }
+}
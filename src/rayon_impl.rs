@@ -0,0 +1,22 @@
+use rayon::prelude::*;
+
+use crate::UTF8;
+
+/// Inputs per rayon task, so short strings are converted in batches instead of one task each.
+const CHUNK: usize = 1024;
+
+impl UTF8 {
+    /// Converts every string from a parallel iterator, keeping the input order for indexed iterators.
+    pub fn par_from_iter<I>(iter: I) -> Vec<UTF8>
+    where
+        I: IntoParallelIterator,
+        I::Item: AsRef<str>,
+    {
+        iter.into_par_iter().map(|s| UTF8::from(s.as_ref())).collect()
+    }
+
+    /// Converts a batch of strings in parallel, in chunks of 1024, keeping their order.
+    pub fn convert_all<S: AsRef<str> + Sync>(inputs: &[S]) -> Vec<UTF8> {
+        inputs.par_iter().with_min_len(CHUNK).map(|s| UTF8::from(s.as_ref())).collect()
+    }
+}
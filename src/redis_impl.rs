@@ -0,0 +1,47 @@
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+use crate::{Blob, UTF8};
+
+impl ToRedisArgs for UTF8 {
+    fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+        out.write_arg(self.as_bytes());
+    }
+}
+
+impl ToSingleRedisArg for UTF8 { }
+
+impl FromRedisValue for UTF8 {
+    /// Bulk and simple strings are copied straight into an inline bucket when they fit;
+    /// every other reply is converted the way `String` would.
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::BulkString(bytes) => Ok(UTF8::from(std::str::from_utf8(bytes)?)),
+            Value::SimpleString(s)   => Ok(UTF8::from(s.as_str())),
+            _ => String::from_redis_value_ref(v).map(UTF8::from),
+        }
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        String::from_redis_value(v).map(UTF8::from)
+    }
+}
+
+impl ToRedisArgs for Blob {
+    fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+        out.write_arg(self.as_bytes());
+    }
+}
+
+impl ToSingleRedisArg for Blob { }
+
+impl FromRedisValue for Blob {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::BulkString(bytes) => Ok(Blob::from(bytes.as_slice())),
+            _ => Vec::<u8>::from_redis_value_ref(v).map(|bytes| Blob::from(bytes.as_slice())),
+        }
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
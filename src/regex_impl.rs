@@ -0,0 +1,22 @@
+use regex::Regex;
+
+use crate::UTF8;
+
+impl UTF8 {
+    /// Returns `true` if `re` matches anywhere in the string.
+    pub fn is_match(&self, re: &Regex) -> bool {
+        re.is_match(self)
+    }
+
+    /// Returns every capture group of the leftmost match, with `None` for groups that did not participate.
+    /// Group 0 is the whole match.
+    pub fn captures_utf8(&self, re: &Regex) -> Option<Vec<Option<UTF8>>> {
+        let captures = re.captures(self)?;
+        Some(captures.iter().map(|group| group.map(|m| UTF8::from(m.as_str()))).collect())
+    }
+
+    /// Splits the string on every match of `re`, like `Regex::split`.
+    pub fn split_regex<'a>(&'a self, re: &'a Regex) -> impl Iterator<Item = UTF8> + 'a {
+        re.split(self).map(UTF8::from)
+    }
+}
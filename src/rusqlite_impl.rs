@@ -0,0 +1,27 @@
+use rusqlite::{Result, ToSql, types::{FromSql, FromSqlResult, ToSqlOutput, ValueRef}};
+use crate::{Blob, UTF8};
+
+impl ToSql for UTF8 {
+    /// Binds the stored bytes in place as `TEXT`, without an intermediate `String`.
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.as_bytes())))
+    }
+}
+
+impl FromSql for UTF8 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value.as_str().map(UTF8::from)
+    }
+}
+
+impl ToSql for Blob {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Blob(self.as_bytes())))
+    }
+}
+
+impl FromSql for Blob {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value.as_blob().map(Blob::from)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::UTF8;
+
+impl UTF8 {
+    /// Formats a float with `ryu` (shortest round-tripping form, e.g. `1.0`, `1e30`, `NaN`, `inf`).
+    /// The result always fits in a `B8`, `B16` or `B64` bucket.
+    pub fn from_float<F: ryu::Float>(value: F) -> Self {
+        Self::from(ryu::Buffer::new().format(value))
+    }
+
+    /// Appends a float formatted with `ryu`.
+    pub fn push_float<F: ryu::Float>(&mut self, value: F) {
+        self.extend_from_slice(ryu::Buffer::new().format(value).as_bytes());
+        debug_assert_eq!(self.check_utf8(), Ok(()));
+    }
+}
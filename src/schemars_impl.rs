@@ -0,0 +1,18 @@
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+use crate::UTF8;
+
+/// `UTF8` is a plain JSON string; the bucket it lands in is an implementation detail, so no `maxLength` is emitted.
+impl JsonSchema for UTF8 {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        String::schema_name()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::{Error, Unexpected, Visitor}};
+use crate::UTF8;
+
+impl Serialize for UTF8 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UTF8 {
+    /// Borrowed and transient strings are copied straight into an inline bucket when they fit;
+    /// owned `String`s are adopted as `Boxed` without copying.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Utf8Visitor)
+    }
+}
+
+struct Utf8Visitor;
+
+impl Visitor<'_> for Utf8Visitor {
+    type Value = UTF8;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<UTF8, E> {
+        Ok(UTF8::from(v))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<UTF8, E> {
+        Ok(UTF8::from(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<UTF8, E> {
+        std::str::from_utf8(v).map(UTF8::from).map_err(|_| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+}
@@ -0,0 +1,94 @@
+//! Serde support for `Str<E>` as a sequence of elements (`feature = "serde"`).
+//!
+//! `UTF8` already serializes as a string, and a blanket `Serialize for Str<E>` would overlap with that impl,
+//! so other element types opt in per field with `#[serde(with = "kaff_sso::serde_seq")]`, or through the
+//! [`Seq`] wrapper inside containers. Deserializing fills a stack buffer when the input's size hint (or its
+//! actual length) fits in 256 elements and lands in the matching bucket; longer input goes to the heap.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Str;
+
+/// Upper bound on what a size hint may preallocate, so a hostile length cannot reserve unbounded memory.
+const MAX_PREALLOC: usize = 4096;
+
+/// Serializes `value` as a sequence of its elements.
+pub fn serialize<E: Serialize, S: Serializer>(value: &Str<E>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(value.elements())
+}
+
+/// Deserializes a sequence of elements into the bucket that fits it.
+pub fn deserialize<'de, E, D>(deserializer: D) -> Result<Str<E>, D::Error>
+where
+    E: Deserialize<'de> + Copy + Default,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(SeqVisitor(PhantomData))
+}
+
+/// A `Str<E>` that serializes as a sequence, for element types in containers such as `Vec<Seq<u16>>`.
+#[derive(Clone)]
+pub struct Seq<E>(pub Str<E>);
+
+impl<E: Serialize> Serialize for Seq<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, E: Deserialize<'de> + Copy + Default> Deserialize<'de> for Seq<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Seq)
+    }
+}
+
+struct SeqVisitor<E>(PhantomData<E>);
+
+impl<'de, E: Deserialize<'de> + Copy + Default> Visitor<'de> for SeqVisitor<E> {
+    type Value = Str<E>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Str<E>, A::Error> {
+        let mut vec = match seq.size_hint() {
+            Some(hint) if hint > 256 => Vec::with_capacity(hint.min(MAX_PREALLOC)),
+
+            _ => {
+                let mut buf = [E::default(); 256];
+                let mut len = 0;
+
+                while len < buf.len() {
+                    match seq.next_element()? {
+                        Some(element) => { buf[len] = element; len += 1 }
+                        None => return Ok(Str::from(&buf[..len])),
+                    }
+                }
+
+                let mut vec = Vec::with_capacity(2 * buf.len());
+                vec.extend_from_slice(&buf);
+                vec
+            }
+        };
+
+        while let Some(element) = seq.next_element()? {
+            vec.push(element);
+        }
+
+        if vec.len() <= 256 {
+            return Ok(Str::from(&vec[..]));
+        }
+
+        let len = vec.len();
+        vec.resize(vec.capacity(), E::default());
+
+        let mut value = Str::Boxed { len: vec.len(), buf: vec.into_boxed_slice() };
+        value.set_len(len);
+        Ok(value)
+    }
+}
@@ -0,0 +1,24 @@
+use smartstring::{SmartString, SmartStringMode};
+
+use crate::UTF8;
+
+impl<M: SmartStringMode> From<UTF8> for SmartString<M> {
+    /// Short strings land in `SmartString`'s inline storage; a `Boxed` buffer is handed over as a `String`.
+    fn from(value: UTF8) -> Self {
+        match value {
+            UTF8::Boxed { .. } => SmartString::from(String::from(value)),
+            UTF8::Empty => SmartString::new(),
+            value => SmartString::from(&*value),
+        }
+    }
+}
+
+impl<M: SmartStringMode> From<SmartString<M>> for UTF8 {
+    /// Copies into an inline bucket when the string fits; longer ones take over the heap buffer.
+    fn from(value: SmartString<M>) -> Self {
+        match value.len() {
+            0..=256 => UTF8::from(value.as_str()),
+            _       => UTF8::from(String::from(value)),
+        }
+    }
+}
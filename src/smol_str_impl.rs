@@ -0,0 +1,26 @@
+use smol_str::SmolStr;
+
+use crate::UTF8;
+
+impl From<UTF8> for SmolStr {
+    fn from(value: UTF8) -> Self {
+        match value {
+            UTF8::Boxed { .. } => SmolStr::from(String::from(value)),
+            UTF8::Empty => SmolStr::new_inline(""),
+            value => SmolStr::new(&*value),
+        }
+    }
+}
+
+impl From<&SmolStr> for UTF8 {
+    fn from(value: &SmolStr) -> Self {
+        UTF8::from(value.as_str())
+    }
+}
+
+impl From<SmolStr> for UTF8 {
+    /// `SmolStr` shares its heap data, so the text is always copied.
+    fn from(value: SmolStr) -> Self {
+        UTF8::from(&value)
+    }
+}
@@ -0,0 +1,36 @@
+use sqlx_core::{database::Database, decode::Decode, encode::{Encode, IsNull}, error::BoxDynError, types::Type};
+use crate::UTF8;
+
+// Every impl defers to the driver's own `str` support, so `UTF8` binds and decodes wherever `&str` does
+// (Postgres, MySQL and SQLite).
+
+impl<DB: Database> Type<DB> for UTF8 where str: Type<DB> {
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for UTF8 where for<'a> &'a str: Encode<'q, DB> {
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<'q, DB>>::encode_by_ref(&&**self, buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        <&str as Encode<'q, DB>>::produces(&&**self)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<'q, DB>>::size_hint(&&**self)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for UTF8 where &'r str: Decode<'r, DB> {
+    /// Borrows the column as `&str` and copies it, so short text lands in an inline bucket without a `String`.
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(UTF8::from(<&str as Decode<'r, DB>>::decode(value)?))
+    }
+}
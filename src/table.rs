@@ -0,0 +1,303 @@
+//! Compact string tables.
+//!
+//! [`encode`] packs a list of strings into one buffer: a header, a `u32` offset per entry and the
+//! concatenated bytes. [`StringTable::parse`] checks such a buffer once and then reads entries in place,
+//! so snapshots of interned strings or shipped symbol tables load without copying.
+//!
+//! With prefix compression each entry stores only what differs from the one before it, behind a one-byte
+//! count of shared leading bytes. Every 16th entry is stored whole, so a lookup rebuilds at most 16 entries.
+//! Compressed entries cannot be borrowed in place and are returned as `UTF8` instead.
+//!
+//! All integers are little-endian. The layout is `[flags: u8][count: u32][offsets: (count + 1) × u32][data]`.
+
+use crate::{Error, UTF8};
+
+const PREFIX_COMPRESSED: u8 = 1;
+const RESTART_INTERVAL: usize = 16;
+const HEADER_LEN: usize = 5;
+
+/// Serializes `values` into a string table, optionally prefix-compressed.
+///
+/// Panics if the table would need more than `u32::MAX` entries or data bytes.
+pub fn encode<I, S>(values: I, prefix_compression: bool) -> Vec<u8>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut offsets = vec![0u32];
+    let mut data    = Vec::new();
+    let mut last    = String::new();
+
+    for (i, value) in values.into_iter().enumerate() {
+        let value = value.as_ref();
+
+        if prefix_compression {
+            let mut shared = 0;
+            if i % RESTART_INTERVAL != 0 {
+                shared = last.bytes().zip(value.bytes()).take(u8::MAX as usize).take_while(|(a, b)| a == b).count();
+                while !value.is_char_boundary(shared) {
+                    shared -= 1;
+                }
+            }
+
+            data.push(shared as u8);
+            data.extend_from_slice(&value.as_bytes()[shared..]);
+
+            last.clear();
+            last.push_str(value);
+        } else {
+            data.extend_from_slice(value.as_bytes());
+        }
+
+        offsets.push(u32::try_from(data.len()).expect("string table data exceeds u32::MAX bytes"));
+    }
+
+    let count = u32::try_from(offsets.len() - 1).expect("string table exceeds u32::MAX entries");
+    let flags = if prefix_compression { PREFIX_COMPRESSED } else { 0 };
+
+    let mut out = Vec::with_capacity(HEADER_LEN + offsets.len() * 4 + data.len());
+    out.push(flags);
+    out.extend_from_slice(&count.to_le_bytes());
+    for offset in offsets {
+        out.extend_from_slice(&offset.to_le_bytes());
+    }
+
+    out.extend_from_slice(&data);
+    out
+}
+
+/// A parsed string table borrowing the buffer it was read from.
+#[derive(Clone, Copy, Debug)]
+pub struct StringTable<'a> {
+    offsets:    &'a [u8],
+    data:       &'a [u8],
+    count:      usize,
+    compressed: bool,
+}
+
+impl<'a> StringTable<'a> {
+    /// Checks the layout of `bytes` and that every entry is valid UTF-8.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        const MALFORMED: Error = Error::Conversion("malformed string table");
+
+        let (&flags, rest) = bytes.split_first().ok_or(MALFORMED)?;
+        if flags & !PREFIX_COMPRESSED != 0 || rest.len() < 4 {
+            return Err(MALFORMED);
+        }
+
+        let (count, rest) = rest.split_at(4);
+        let count         = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+        let offsets_len   = count.checked_add(1).and_then(|n| n.checked_mul(4)).ok_or(MALFORMED)?;
+        if rest.len() < offsets_len {
+            return Err(MALFORMED);
+        }
+
+        let (offsets, data) = rest.split_at(offsets_len);
+        let table = Self { offsets, data, count, compressed: flags & PREFIX_COMPRESSED != 0 };
+
+        if table.offset(0) != 0 || table.offset(count) != data.len() {
+            return Err(MALFORMED);
+        }
+
+        let mut last = UTF8::Empty;
+        for i in 0..count {
+            let (start, end) = (table.offset(i), table.offset(i + 1));
+            if start > end || end > data.len() {
+                return Err(MALFORMED);
+            }
+
+            if !table.compressed {
+                std::str::from_utf8(&data[start..end])?;
+                continue;
+            }
+
+            let (&shared, suffix) = data[start..end].split_first().ok_or(MALFORMED)?;
+            let shared = shared as usize;
+            if (i % RESTART_INTERVAL == 0 && shared != 0) || shared > last.len() || !last.is_char_boundary(shared) {
+                return Err(MALFORMED);
+            }
+
+            std::str::from_utf8(suffix)?;
+            last = join(&last, shared, suffix);
+        }
+
+        Ok(table)
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if the entries are prefix-compressed.
+    pub fn is_prefix_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Borrows entry `index` in place. Returns `None` past the end, and always for prefix-compressed tables.
+    pub fn view(&self, index: usize) -> Option<&'a str> {
+        if self.compressed {
+            return None;
+        }
+
+        self.raw(index).map(|bytes| std::str::from_utf8(bytes).expect("string table entry was checked by parse"))
+    }
+
+    /// Returns entry `index` as a `UTF8`, rebuilding it from its restart point if the table is compressed.
+    pub fn get(&self, index: usize) -> Option<UTF8> {
+        if !self.compressed {
+            return self.view(index).map(UTF8::from);
+        }
+
+        if index >= self.count {
+            return None;
+        }
+
+        let mut iter = Iter { table: *self, next: index - index % RESTART_INTERVAL, last: UTF8::Empty };
+        iter.nth(index % RESTART_INTERVAL)
+    }
+
+    /// Iterates over the entries in order.
+    pub fn iter(&self) -> Iter<'a> {
+        Iter { table: *self, next: 0, last: UTF8::Empty }
+    }
+
+    fn offset(&self, index: usize) -> usize {
+        let at = index * 4;
+        u32::from_le_bytes(self.offsets[at..at + 4].try_into().unwrap()) as usize
+    }
+
+    fn raw(&self, index: usize) -> Option<&'a [u8]> {
+        (index < self.count).then(|| &self.data[self.offset(index)..self.offset(index + 1)])
+    }
+}
+
+impl<'a> IntoIterator for &StringTable<'a> {
+    type Item     = UTF8;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`StringTable`].
+#[derive(Clone)]
+pub struct Iter<'a> {
+    table: StringTable<'a>,
+    next:  usize,
+    last:  UTF8,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = UTF8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let raw = self.table.raw(self.next)?;
+        self.next += 1;
+
+        if !self.table.compressed {
+            return Some(UTF8::from(std::str::from_utf8(raw).expect("string table entry was checked by parse")));
+        }
+
+        self.last = join(&self.last, raw[0] as usize, &raw[1..]);
+        Some(self.last.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.table.count - self.next.min(self.table.count);
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> { }
+
+fn join(prev: &UTF8, shared: usize, suffix: &[u8]) -> UTF8 {
+    let mut next = UTF8::from(&prev.elements()[..shared]);
+    next.extend_from_slice(suffix);
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a raw table from its parts, bypassing `encode`.
+    fn raw_table(flags: u8, offsets: &[u32], data: &[u8]) -> Vec<u8> {
+        let mut out = vec![flags];
+        out.extend_from_slice(&(offsets.len() as u32 - 1).to_le_bytes());
+        offsets.iter().for_each(|offset| out.extend_from_slice(&offset.to_le_bytes()));
+        out.extend_from_slice(data);
+        out
+    }
+
+    fn round_trip(values: &[&str], prefix_compression: bool) {
+        let bytes = encode(values, prefix_compression);
+        let table = StringTable::parse(&bytes).unwrap();
+
+        assert_eq!(table.len(), values.len());
+        assert_eq!(table.is_prefix_compressed(), prefix_compression);
+        assert!(table.iter().eq(values.iter().map(|&value| UTF8::from(value))));
+
+        for (i, &value) in values.iter().enumerate() {
+            assert_eq!(table.get(i).as_deref(), Some(value));
+            assert_eq!(table.view(i), (!prefix_compression).then_some(value));
+        }
+
+        assert_eq!(table.get(values.len()), None);
+        assert_eq!(table.view(values.len()), None);
+    }
+
+    #[test]
+    fn plain_tables_round_trip() {
+        round_trip(&[], false);
+        round_trip(&["", "alpha", "", "b\u{e9}ta", "\u{1f600}"], false);
+    }
+
+    #[test]
+    fn compressed_tables_round_trip_across_restarts() {
+        let values: Vec<String> = (0..40).map(|i| format!("module::path::item_{i:02}")).collect();
+        let values: Vec<&str>   = values.iter().map(String::as_str).collect();
+
+        round_trip(&values, true);
+        round_trip(&values, false);
+    }
+
+    #[test]
+    fn compressed_prefixes_stop_at_char_boundaries() {
+        // "\u{e9}" and "\u{e8}" share their first byte, which must not be split off on its own.
+        round_trip(&["caf\u{e9}", "caf\u{e8}", "caf\u{e8}s", "\u{65e5}\u{672c}", "\u{65e5}\u{672c}\u{8a9e}", "\u{65e5}"], true);
+    }
+
+    #[test]
+    fn out_of_range_offsets_are_malformed() {
+        let bytes = raw_table(0, &[0, 100, 5], b"hello");
+        assert!(matches!(StringTable::parse(&bytes), Err(Error::Conversion(_))));
+    }
+
+    #[test]
+    fn malformed_tables_are_rejected() {
+        let cases = [
+            Vec::new(),
+            vec![0, 1, 0],
+            raw_table(2, &[0, 5], b"hello"),
+            raw_table(0, &[0, 5], b"hell"),
+            raw_table(0, &[1, 5], b"hello"),
+            raw_table(0, &[0, 4, 2, 5], b"hello"),
+            raw_table(0, &[0, 2], &[0xc3, 0x28]),
+            raw_table(PREFIX_COMPRESSED, &[0, 0], b""),
+            raw_table(PREFIX_COMPRESSED, &[0, 3], b"\x01ab"),
+            raw_table(PREFIX_COMPRESSED, &[0, 3, 5], b"\x00ab\x03c"),
+            raw_table(PREFIX_COMPRESSED, &[0, 3, 5], "\x00\u{e9}\x01c".as_bytes()),
+        ];
+
+        for bytes in cases {
+            assert!(StringTable::parse(&bytes).is_err(), "{bytes:?}");
+        }
+    }
+}
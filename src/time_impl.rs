@@ -0,0 +1,22 @@
+use time::OffsetDateTime;
+use time::error::Format;
+use time::format_description::well_known::Rfc3339;
+use time::formatting::Formattable;
+
+use crate::UTF8;
+use crate::io::Utf8Writer;
+
+impl UTF8 {
+    /// Renders `value` as RFC 3339, e.g. `2024-05-01T12:30:00.5Z`.
+    pub fn from_timestamp(value: &OffsetDateTime) -> Result<Self, Format> {
+        Self::format_datetime(value, &Rfc3339)
+    }
+
+    /// Renders `value` with any `time` format description, without an intermediate `String`.
+    /// Literal bytes in the description that are not valid UTF-8 are reported as `Format::StdIo`.
+    pub fn format_datetime(value: &OffsetDateTime, format: &(impl Formattable + ?Sized)) -> Result<Self, Format> {
+        let mut out = Utf8Writer::new();
+        value.format_into(&mut out, format)?;
+        out.finish().map_err(Format::StdIo)
+    }
+}
@@ -0,0 +1,129 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::io::Utf8Writer;
+use crate::{Blob, Str, UTF8};
+
+/// Async counterparts of the `kaff_sso::io` readers, for any `AsyncRead`.
+pub trait AsyncReadUtf8Ext: AsyncRead + Unpin {
+    /// Reads exactly `len` bytes into a `UTF8`, through a stack buffer when they fit in an inline bucket.
+    /// Fails with `UnexpectedEof` if the stream ends first and with `InvalidData` if the bytes are not UTF-8.
+    fn read_utf8_exact(&mut self, len: usize) -> impl Future<Output = io::Result<UTF8>> + Send where Self: Send {
+        async move {
+            if len <= 256 {
+                let mut buf = [0u8; 256];
+                self.read_exact(&mut buf[..len]).await?;
+
+                return match std::str::from_utf8(&buf[..len]) {
+                    Ok(s)  => Ok(UTF8::from(s)),
+                    Err(_) => Err(invalid_data()),
+                };
+            }
+
+            let mut vec = vec![0; len];
+            self.read_exact(&mut vec).await?;
+            String::from_utf8(vec).map(UTF8::from).map_err(|_| invalid_data())
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncReadUtf8Ext for R { }
+
+/// Async counterparts of [`read_line_utf8`](crate::io::read_line_utf8) and
+/// [`read_until_into`](crate::io::read_until_into), for any `AsyncBufRead`.
+pub trait AsyncBufReadUtf8Ext: AsyncBufRead + Unpin {
+    /// Reads one line, including its `\n` if present, straight into a `UTF8`. Returns `Empty` at end of input.
+    fn read_line_utf8(&mut self) -> impl Future<Output = io::Result<UTF8>> + Send where Self: Send {
+        async move {
+            let mut line = Blob::default();
+            self.read_until_into(b'\n', &mut line).await?;
+
+            match std::str::from_utf8(line.as_bytes()) {
+                Ok(_)  => Ok(line.0),
+                Err(_) => Err(invalid_data()),
+            }
+        }
+    }
+
+    /// Appends bytes up to and including `delim` to `buf`, returning how many were read.
+    fn read_until_into(&mut self, delim: u8, buf: &mut Blob) -> impl Future<Output = io::Result<usize>> + Send where Self: Send {
+        async move {
+            let mut read = 0;
+
+            loop {
+                let (done, used) = {
+                    let available = self.fill_buf().await?;
+
+                    match available.iter().position(|&b| b == delim) {
+                        Some(i) => {
+                            buf.0.extend_from_slice(&available[..=i]);
+                            (true, i + 1)
+                        }
+
+                        None => {
+                            buf.0.extend_from_slice(available);
+                            (available.is_empty(), available.len())
+                        }
+                    }
+                };
+
+                self.consume(used);
+                read += used;
+
+                if done {
+                    return Ok(read);
+                }
+            }
+        }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin + ?Sized> AsyncBufReadUtf8Ext for R { }
+
+/// Writes stored bytes to any `AsyncWrite` without going through `Deref`.
+pub trait AsyncWriteUtf8Ext: AsyncWrite + Unpin {
+    /// Writes all of `value`'s bytes.
+    fn write_utf8(&mut self, value: &Str<u8>) -> impl Future<Output = io::Result<()>> + Send where Self: Send {
+        async move {
+            self.write_all(value.elements()).await
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> AsyncWriteUtf8Ext for W { }
+
+/// Appends the written bytes, like the `std::io::Write` impl. Never pending.
+impl AsyncWrite for Blob {
+    fn poll_write(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(std::io::Write::write(self.get_mut(), buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Validates and collects the written bytes, like the `std::io::Write` impl. Never pending.
+impl AsyncWrite for Utf8Writer {
+    fn poll_write(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(std::io::Write::write(self.get_mut(), buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn invalid_data() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
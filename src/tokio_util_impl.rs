@@ -0,0 +1,83 @@
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{Str, UTF8};
+
+/// A line codec like `LinesCodec`, yielding `UTF8` instead of `String`.
+///
+/// Lines are split on `\n` with a trailing `\r` removed, and lines that fit are copied straight from
+/// the read buffer into an inline bucket. Lines that are not valid UTF-8 fail with `InvalidData`.
+#[derive(Clone, Debug, Default)]
+pub struct Utf8Lines {
+    next_index: usize,
+}
+
+impl Utf8Lines {
+    /// Creates a decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Decoder for Utf8Lines {
+    type Item  = UTF8;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<UTF8>, Self::Error> {
+        match buf[self.next_index..].iter().position(|&b| b == b'\n') {
+            Some(i) => {
+                let end = self.next_index + i;
+                self.next_index = 0;
+
+                let line = buf.split_to(end + 1);
+                to_line(&line[..end]).map(Some)
+            }
+
+            None => {
+                self.next_index = buf.len();
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns the unterminated last line, if any, once the stream ends.
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<UTF8>, Self::Error> {
+        match self.decode(buf)? {
+            Some(line) => Ok(Some(line)),
+            None if buf.is_empty() => Ok(None),
+            None => {
+                self.next_index = 0;
+                to_line(&buf.split()).map(Some)
+            }
+        }
+    }
+}
+
+/// Writes the line followed by `\n`.
+impl Encoder<UTF8> for Utf8Lines {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, line: UTF8, buf: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&line, buf)
+    }
+}
+
+impl Encoder<&Str<u8>> for Utf8Lines {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, line: &Str<u8>, buf: &mut BytesMut) -> Result<(), Self::Error> {
+        buf.reserve(line.len() + 1);
+        buf.put_slice(line.elements());
+        buf.put_u8(b'\n');
+        Ok(())
+    }
+}
+
+fn to_line(line: &[u8]) -> std::io::Result<UTF8> {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+    match std::str::from_utf8(line) {
+        Ok(line) => Ok(UTF8::from(line)),
+        Err(_)   => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "line is not valid UTF-8")),
+    }
+}
@@ -0,0 +1,67 @@
+use ufmt::{Formatter, uDebug, uDisplay, uWrite};
+use crate::UTF8;
+
+impl uDisplay for UTF8 {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self)
+    }
+}
+
+impl uDebug for UTF8 {
+    /// Quoted, with the same escapes as `core::fmt::Debug for str`; unescaped runs are written in one piece.
+    /// `char::escape_debug` also escapes `'`, which only `Debug for char` does, so it is left as-is here.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let s = &**self;
+        let mut from = 0;
+
+        f.write_char('"')?;
+        for (i, c) in s.char_indices() {
+            let escaped = c.escape_debug();
+            if c != '\'' && escaped.len() != 1 {
+                f.write_str(&s[from..i])?;
+                for c in escaped { f.write_char(c)? }
+                from = i + c.len_utf8();
+            }
+        }
+        f.write_str(&s[from..])?;
+        f.write_char('"')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use ufmt::{uWrite, uwrite};
+
+    use crate::UTF8;
+
+    struct Out(String);
+
+    impl uWrite for Out {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    fn udebug(text: &str) -> String {
+        let mut out = Out(String::new());
+        let Ok(()) = uwrite!(out, "{:?}", UTF8::from(text));
+        out.0
+    }
+
+    #[test]
+    fn debug_matches_std() {
+        for text in ["", "plain", "it's", "say \"hi\"", "tab\tnew\nline\\", "\0\u{7f}\u{200b}", "\u{301}e", "e\u{301}", "日本語 ✓"] {
+            assert_eq!(udebug(text), format!("{text:?}"), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn single_quote_is_not_escaped() {
+        assert_eq!(udebug("it's"), "\"it's\"");
+    }
+}
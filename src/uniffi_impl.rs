@@ -0,0 +1,43 @@
+use uniffi::{FfiConverter, MetadataBuffer, Result, RustBuffer, check_remaining, metadata::codes};
+use uniffi::deps::bytes::{Buf, BufMut};
+use crate::UTF8;
+
+/// Passes `UTF8` over the FFI exactly like `String`, so generated Kotlin/Swift bindings see a plain string.
+/// `Boxed` values are handed over without copying, and short strings are lifted straight into inline buckets.
+unsafe impl<UT> FfiConverter<UT> for UTF8 {
+    type FfiType = RustBuffer;
+
+    fn lower(obj: UTF8) -> Self::FfiType {
+        RustBuffer::from_vec(String::from(obj).into_bytes())
+    }
+
+    // Like `String`, the buffer is trusted to be a string previously lowered by Rust.
+    fn try_lift(v: Self::FfiType) -> Result<UTF8> {
+        let v = v.destroy_into_vec();
+
+        if v.len() <= 256 {
+            return Ok(UTF8::from(v.as_slice()));
+        }
+
+        Ok(UTF8::from(unsafe { String::from_utf8_unchecked(v) }))
+    }
+
+    fn write(obj: UTF8, buf: &mut Vec<u8>) {
+        buf.put_i32(i32::try_from(obj.len()).unwrap());
+        buf.put(obj.as_bytes());
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<UTF8> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        check_remaining(buf, len)?;
+
+        let value = UTF8::from(std::str::from_utf8(&buf[..len])?);
+        buf.advance(len);
+        Ok(value)
+    }
+
+    const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(codes::TYPE_STRING);
+}
+
+uniffi::derive_ffi_traits!(blanket UTF8);
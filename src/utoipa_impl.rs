@@ -0,0 +1,17 @@
+use utoipa::openapi::{RefOr, schema::Schema};
+use utoipa::{PartialSchema, ToSchema};
+use std::borrow::Cow;
+use crate::UTF8;
+
+impl PartialSchema for UTF8 {
+    fn schema() -> RefOr<Schema> {
+        String::schema()
+    }
+}
+
+/// Registered under the `UTF8` name; the schema itself is identical to `String`'s.
+impl ToSchema for UTF8 {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("UTF8")
+    }
+}
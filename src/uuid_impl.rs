@@ -0,0 +1,41 @@
+use uuid::Uuid;
+use uuid::fmt::{Braced, Hyphenated, Simple, Urn};
+
+use crate::UTF8;
+
+impl UTF8 {
+    /// Formats `uuid` in lowercase hyphenated form (36 bytes, in a `B64` bucket).
+    /// Use `UTF8::from(uuid.simple())`, `urn()` or `braced()` for the other forms.
+    pub fn from_uuid(uuid: &Uuid) -> Self {
+        Self::from(uuid.hyphenated())
+    }
+
+    /// Parses the string as a UUID in any of the forms `Uuid::try_parse` accepts.
+    pub fn parse_uuid(&self) -> Result<Uuid, uuid::Error> {
+        Uuid::try_parse(self)
+    }
+}
+
+impl From<Hyphenated> for UTF8 {
+    fn from(value: Hyphenated) -> Self {
+        Self::from(&*value.encode_lower(&mut [0; Hyphenated::LENGTH]))
+    }
+}
+
+impl From<Simple> for UTF8 {
+    fn from(value: Simple) -> Self {
+        Self::from(&*value.encode_lower(&mut [0; Simple::LENGTH]))
+    }
+}
+
+impl From<Urn> for UTF8 {
+    fn from(value: Urn) -> Self {
+        Self::from(&*value.encode_lower(&mut [0; Urn::LENGTH]))
+    }
+}
+
+impl From<Braced> for UTF8 {
+    fn from(value: Braced) -> Self {
+        Self::from(&*value.encode_lower(&mut [0; Braced::LENGTH]))
+    }
+}
@@ -0,0 +1,40 @@
+use v8::{Isolate, Local, NewStringType, PinScope, ValueView, ValueViewData};
+use crate::UTF8;
+
+impl UTF8 {
+    /// Reads a V8 string through a `ValueView`, without an intermediate `String`.
+    ///
+    /// One-byte strings are copied as-is when ASCII and transcoded from Latin-1 otherwise;
+    /// two-byte strings are transcoded from UTF-16, replacing unpaired surrogates with `U+FFFD`.
+    pub fn from_v8_string(isolate: &mut Isolate, string: Local<v8::String>) -> Self {
+        let view = ValueView::new(isolate, string);
+
+        match view.data() {
+            ValueViewData::OneByte(bytes) => Self::from_latin1(bytes),
+            ValueViewData::TwoByte(units) => Self::from_utf16_lossy(units),
+        }
+    }
+
+    /// Creates a V8 string, using the one-byte constructor for ASCII contents.
+    pub fn to_v8_string<'s>(&self, scope: &PinScope<'s, '_, ()>) -> Option<Local<'s, v8::String>> {
+        if self.is_empty() {
+            return Some(v8::String::empty(scope));
+        }
+
+        let bytes = self.as_bytes();
+        if bytes.is_ascii() {
+            return v8::String::new_from_one_byte(scope, bytes, NewStringType::Normal);
+        }
+
+        v8::String::new_from_utf8(scope, bytes, NewStringType::Normal)
+    }
+
+    /// Like [`to_v8_string`](Self::to_v8_string), but hands an ASCII `Boxed` buffer to V8 as an
+    /// external one-byte string instead of copying it.
+    pub fn into_v8_string<'s>(self, scope: &PinScope<'s, '_, ()>) -> Option<Local<'s, v8::String>> {
+        match self {
+            UTF8::Boxed { buf, len } if len == buf.len() && buf.is_ascii() => v8::String::new_external_onebyte(scope, buf),
+            value => value.to_v8_string(scope),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use valuable::{Valuable, Value, Visit};
+use crate::UTF8;
+
+// `tracing::Value` is sealed, so spans and events pick `UTF8` up through `valuable` instead:
+// `tracing::info!(name = tracing::field::valuable(&name))` (requires `--cfg tracing_unstable`).
+
+impl Valuable for UTF8 {
+    fn as_value(&self) -> Value<'_> {
+        Value::String(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_value(self.as_value());
+    }
+}